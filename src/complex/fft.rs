@@ -0,0 +1,125 @@
+//! Fast Fourier Transform (`complex` feature)
+//!
+//! * `fft` : $X_k = \sum_{j=0}^{n-1} x_j e^{-2\pi i jk/n}$
+//! * `ifft` : inverse of `fft` (includes the $1/n$ factor)
+//! * `rfft` : `fft` of a real signal
+//!
+//! Any length is supported: radix-2 for powers of two, Bluestein's algorithm otherwise.
+//!
+//! # Example
+//! ```
+//! use peroxide::fuga::*;
+//!
+//! # #[cfg(feature = "complex")]
+//! # {
+//! let x = vec![1f64, 2f64, 3f64];
+//! let y = rfft(&x);
+//! assert!((y[0] - C64::new(6f64, 0f64)).norm() < 1e-12);
+//!
+//! let z = ifft(&y);
+//! for (a, b) in x.iter().zip(z.iter()) {
+//!     assert!((a - b.re).abs() < 1e-12);
+//! }
+//! # }
+//! ```
+
+use crate::complex::C64;
+use std::f64::consts::PI;
+
+/// Discrete Fourier transform
+pub fn fft(x: &[C64]) -> Vec<C64> {
+    dft(x, false)
+}
+
+/// Inverse discrete Fourier transform
+pub fn ifft(x: &[C64]) -> Vec<C64> {
+    let n = x.len() as f64;
+    dft(x, true).into_iter().map(|z| z / n).collect()
+}
+
+/// Discrete Fourier transform of real signal (full spectrum)
+pub fn rfft(x: &[f64]) -> Vec<C64> {
+    let z = x.iter().map(|&t| C64::new(t, 0f64)).collect::<Vec<C64>>();
+    fft(&z)
+}
+
+/// Unnormalized DFT (`inverse` flips the sign of the exponent)
+fn dft(x: &[C64], inverse: bool) -> Vec<C64> {
+    let n = x.len();
+    if n <= 1 {
+        return x.to_vec();
+    }
+    if n.is_power_of_two() {
+        let mut a = x.to_vec();
+        radix2(&mut a, inverse);
+        a
+    } else {
+        bluestein(x, inverse)
+    }
+}
+
+/// In-place iterative radix-2 FFT (length should be a power of two)
+fn radix2(a: &mut [C64], inverse: bool) {
+    let n = a.len();
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1f64 } else { -1f64 };
+    let mut len = 2usize;
+    while len <= n {
+        let w_len = C64::from_polar(1f64, sign * 2f64 * PI / len as f64);
+        for start in (0..n).step_by(len) {
+            let mut w = C64::new(1f64, 0f64);
+            for k in 0..len / 2 {
+                let u = a[start + k];
+                let v = a[start + k + len / 2] * w;
+                a[start + k] = u + v;
+                a[start + k + len / 2] = u - v;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bluestein's chirp-z algorithm for arbitrary length
+fn bluestein(x: &[C64], inverse: bool) -> Vec<C64> {
+    let n = x.len();
+    let sign = if inverse { 1f64 } else { -1f64 };
+    // w_k = exp(sign * i pi k^2 / n) (k^2 mod 2n for accuracy)
+    let w = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            C64::from_polar(1f64, sign * PI * k2 as f64 / n as f64)
+        })
+        .collect::<Vec<C64>>();
+
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a = vec![C64::new(0f64, 0f64); m];
+    let mut b = vec![C64::new(0f64, 0f64); m];
+    for k in 0..n {
+        a[k] = x[k] * w[k];
+    }
+    b[0] = w[0].conj();
+    for k in 1..n {
+        b[k] = w[k].conj();
+        b[m - k] = w[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    let mut c = a.iter().zip(b.iter()).map(|(p, q)| p * q).collect::<Vec<C64>>();
+    radix2(&mut c, true);
+
+    (0..n).map(|k| w[k] * c[k] / m as f64).collect()
+}
@@ -64,6 +64,28 @@ impl FPVector for Vec<Complex<f64>> {
             .collect()
     }
 
+    fn zip_with3<F>(&self, f: F, b: &Self, c: &Self) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        assert!(
+            self.len() == b.len() && self.len() == c.len(),
+            "zip_with3: length mismatch"
+        );
+        (0..self.len()).map(|i| f(self[i], b[i], c[i])).collect()
+    }
+
+    fn zip_with4<F>(&self, f: F, b: &Self, c: &Self, d: &Self) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar, Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        assert!(
+            self.len() == b.len() && self.len() == c.len() && self.len() == d.len(),
+            "zip_with4: length mismatch"
+        );
+        (0..self.len()).map(|i| f(self[i], b[i], c[i], d[i])).collect()
+    }
+
     fn reduce<F, T>(&self, init: T, f: F) -> Self::Scalar
     where
         F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
@@ -72,6 +94,26 @@ impl FPVector for Vec<Complex<f64>> {
         self.iter().fold(init.into(), |x, &y| f(x, y))
     }
 
+    fn reduce1<F>(&self, f: F) -> Option<Self::Scalar>
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        self.iter().copied().reduce(f)
+    }
+
+    fn scan<F, T>(&self, init: T, f: F) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
+        T: Into<Self::Scalar>,
+    {
+        self.iter()
+            .scan(init.into(), |acc, &x| {
+                *acc = f(*acc, x);
+                Some(*acc)
+            })
+            .collect()
+    }
+
     fn filter<F>(&self, f: F) -> Self
     where
         F: Fn(Self::Scalar) -> bool,
@@ -0,0 +1,44 @@
+//! Only the essentials.
+//!
+//! # Philosophy
+//!
+//! `fuga` and `prelude` import the whole crate, which may clash with names in your code.
+//! `essentials` re-exports only the core types & functions:
+//!
+//! * `Matrix`, `Shape` (`Row`, `Col`) and constructors (`matrix`, `ml_matrix`, `zeros`, `eye`)
+//! * Vector generators (`seq`, `linspace`)
+//! * Vector traits (`Vector`, `Normed`, `Norm`, `InnerProduct`)
+//! * Basic statistics (`Statistics`, `cov`, `cor`)
+//! * `Printable`
+//!
+//! Macros (`c!`, `matrix!`, ...) are exported at the crate root, so use `#[macro_use]` as usual.
+//!
+//! # Usage
+//!
+//! ```
+//! #[macro_use]
+//! extern crate peroxide;
+//! use peroxide::essentials::*;
+//!
+//! fn main() {
+//!     let a = ml_matrix("1 2;3 4");
+//!     let b = &a * &eye(2);
+//!     assert_eq!(a, b);
+//!
+//!     let x = c!(1, 2, 3);
+//!     assert_eq!(x.mean(), 2f64);
+//!     assert_eq!(x.norm(Norm::L1), 6f64);
+//!     zeros(2, 2).print();
+//! }
+//! ```
+
+pub use crate::structure::matrix::{matrix, ml_matrix, Col, Matrix, Row, Shape};
+
+pub use crate::traits::math::{InnerProduct, Norm, Normed, Vector};
+
+pub use crate::util::{
+    non_macro::{eye, linspace, seq, zeros},
+    print::Printable,
+};
+
+pub use crate::statistics::stat::{cor, cov, Statistics};
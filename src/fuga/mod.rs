@@ -156,12 +156,15 @@ pub use peroxide_num::{ExpLogOps, PowOps, TrigOps};
 pub use crate::traits::{
     fp::{FPMatrix, FPVector},
     general::Algorithm,
-    math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, Vector, VectorProduct},
+    math::{
+        InnerProduct, LinearOp, MatrixProduct, Norm, Normed, StableExpLogOps, Vector,
+        VectorProduct,
+    },
     mutable::{MutFP, MutMatrix},
-    num::Real,
+    num::{MatrixScalar, Real},
     pointer::{MatrixPtr, Oxide, Redox, RedoxCommon},
     stable::StableFn,
-    sugar::{Scalable, ScalableMut, VecOps, ConvToMat},
+    sugar::{Scalable, ScalableMut, VecOps, ConvToMat, CumOps},
 };
 
 #[allow(unused_imports)]
@@ -171,9 +174,16 @@ pub use crate::structure::{
     vector::*, 
     dataframe::*,
     ad::*,
+    interval::*,
     //complex::C64,
 };
 
+#[cfg(feature = "mmap")]
+pub use crate::structure::mmap::MmapMatrix;
+
+#[cfg(feature = "complex")]
+pub use crate::complex::{fft::*, C64};
+
 pub use crate::util::{api::*, low_level::*, non_macro::*, print::*, useful::*, wrapper::*};
 
 #[allow(unused_imports)]
@@ -184,7 +194,8 @@ pub use crate::special::function::*;
 
 #[allow(unused_imports)]
 pub use crate::numerical::{
-    eigen::*, integral::*, interp::*, ode::*, optimize::*, root::*, spline::*, utils::*,
+    eigen::*, integral::*, interp::*, ode::*, optimize::*, root::*, signal::*, spline::*,
+    utils::*,
 };
 
 #[allow(unused_imports)]
@@ -117,6 +117,10 @@
 //!     ```bash
 //!     cargo add peroxide --features "O3 plot nc csv parquet"
 //!     ```
+//! 8. `no_std` (+ `alloc`) core subset
+//!     ```bash
+//!     cargo add peroxide --no-default-features
+//!     ```
 //!
 //! ## Import all at once
 //!
@@ -125,6 +129,9 @@
 //! * [`prelude`](prelude/index.html) : To simple use
 //! * [`fuga`](fuga/index.html) : To control numerical algorithms
 //!
+//! If you want to avoid importing the whole crate, [`essentials`](essentials/index.html)
+//! re-exports only the core types & functions.
+//!
 //! To see differences, follow above two links.
 //!
 //! You can import all functions & structures at once
@@ -162,10 +169,17 @@
 //!     * To read parquet files in Python, you can use the `pandas` and `pyarrow` libraries.
 //!
 //!     * A template for Python code that works with netcdf files can be found in the [Socialst](https://github.com/Axect/Socialst/blob/master/Templates/PyPlot_Template/nc_plot.py) repository.
+//!
+//! * Without the default `std` feature, peroxide is `no_std` (requires `alloc`). Only `Matrix` & vector arithmetic, norms, decompositions, `eigen` and basic statistics are available.
+//!   `fuga` and `prelude` are disabled, so import from modules directly (e.g. `peroxide::structure::matrix::*`).
+//!   RNG, file I/O, special functions and other numerical algorithms require `std`.
 
 //!
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+extern crate alloc;
+
 #[cfg(feature = "O3")]
 extern crate blas;
 
@@ -178,12 +192,17 @@ extern crate pyo3;
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
 extern crate rand;
 
 // extern crate json;
 
+#[cfg(feature = "std")]
 extern crate order_stat;
 
+#[cfg(feature = "std")]
 extern crate puruspe;
 
 extern crate matrixmultiply;
@@ -191,15 +210,24 @@ extern crate matrixmultiply;
 #[cfg(feature = "nc")]
 extern crate netcdf;
 
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+
 extern crate peroxide_ad;
 
 #[macro_use]
 pub mod macros;
 
+#[cfg(feature = "std")]
+pub mod essentials;
+#[cfg(feature = "std")]
 pub mod fuga;
+#[cfg(feature = "std")]
 pub mod ml;
 pub mod numerical;
+#[cfg(feature = "std")]
 pub mod prelude;
+#[cfg(feature = "std")]
 pub mod special;
 pub mod statistics;
 pub mod structure;
@@ -45,14 +45,14 @@ macro_rules! zeros {
 #[macro_export]
 macro_rules! rand {
     () => {{
-        let mut rng = thread_rng();
+        let mut rng = $crate::statistics::rand::global_rng();
         rng.gen_range(0f64..=1f64)
     }};
 
     ( $m:expr, $n:expr ) => {{
         let r = $m;
         let c = $n;
-        let mut rng = thread_rng();
+        let mut rng = $crate::statistics::rand::global_rng();
         let mut m = matrix(vec![0f64; r * c], r, c, Row);
         for i in 0..r {
             for j in 0..c {
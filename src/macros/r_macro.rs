@@ -278,7 +278,7 @@ macro_rules! runif {
         let n: usize = $x0;
         let mut v = vec![0f64; n];
 
-        let mut rng = thread_rng();
+        let mut rng = $crate::statistics::rand::global_rng();
 
         for i in 0..n {
             v[i] = rng.gen_range($start as f64..=$end as f64);
@@ -1,16 +1,51 @@
 //! To find Eigenvalue & Eigenvector
 //!
 //! * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+//!
+//! * `eigen` : All eigenpairs of a dense symmetric matrix (Jacobi method)
+//! * `lanczos` : Largest or smallest eigenpairs of a large symmetric operator (dense or sparse)
 
 pub use self::EigenMethod::*;
-use crate::structure::matrix::Matrix;
-use crate::util::non_macro::eye_shape;
+use crate::structure::matrix::{matrix, Matrix, Shape};
+use crate::traits::math::{InnerProduct, LinearOp, Norm, Normed};
+use crate::util::non_macro::{eye_shape, zeros};
+use alloc::{vec, vec::Vec};
+use anyhow::{bail, Result};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use rand::Rng;
+#[cfg(not(feature = "std"))]
+use rand::{rngs::SmallRng, SeedableRng};
 
 #[derive(Debug, Copy, Clone)]
 pub enum EigenMethod {
     Jacobi,
 }
 
+/// Which end of the spectrum `lanczos` returns
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LanczosWhich {
+    /// Largest algebraic eigenvalues (descending order)
+    LargestAlgebraic,
+    /// Smallest algebraic eigenvalues (ascending order)
+    SmallestAlgebraic,
+}
+
+/// Error for `lanczos`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LanczosError {
+    NotConverge(usize, usize), // converged, requested
+}
+
+impl fmt::Display for LanczosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LanczosError::NotConverge(c, k) => write!(f, "Only {} of {} Ritz pairs converged", c, k),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Eigen {
     pub eigenvalue: Vec<f64>,
@@ -34,6 +69,19 @@ pub fn eigen(m: &Matrix, em: EigenMethod) -> Eigen {
     }
 }
 
+/// Random unit vector to start iterative eigensolvers
+///
+/// Drawn from `global_rng` (fixed-seed `SmallRng` without `std`),
+/// so it is almost surely not orthogonal to the wanted eigenvector.
+pub(crate) fn random_unit_vector(n: usize) -> Vec<f64> {
+    #[cfg(feature = "std")]
+    let mut rng = crate::statistics::rand::global_rng();
+    #[cfg(not(feature = "std"))]
+    let mut rng = SmallRng::seed_from_u64(0);
+    let v = (0..n).map(|_| rng.gen_range(-1f64..1f64)).collect::<Vec<f64>>();
+    v.normalize(Norm::L2)
+}
+
 // =============================================================================
 // Jacobi Method
 // =============================================================================
@@ -192,3 +240,362 @@ fn eigsrt(d: &mut Vec<f64>, v: &mut Matrix) {
         }
     }
 }
+
+// =============================================================================
+// Shifted QR Method
+// =============================================================================
+/// Real eigenvalues of a general square matrix (descending order)
+///
+/// Reduces to upper Hessenberg form (`elmhes`) and applies the shifted QR method (`hqr`).
+/// Returns `None` if the QR iteration does not converge or if an eigenvalue is complex
+/// (imaginary part above `imag_tol`).
+///
+/// * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+pub(crate) fn real_eigenvalues(m: &Matrix, imag_tol: f64) -> Option<Vec<f64>> {
+    let mut a = m.clone();
+    elmhes(&mut a);
+    let mut w = hqr(&mut a)?;
+    if w.iter().any(|&(_, im)| im.abs() > imag_tol) {
+        return None;
+    }
+    w.sort_by(|x, y| y.0.partial_cmp(&x.0).unwrap());
+    Some(w.into_iter().map(|(re, _)| re).collect())
+}
+
+/// Reduction to upper Hessenberg form by elimination with pivoting
+///
+/// Entries below the subdiagonal are set to zero.
+///
+/// * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+fn elmhes(a: &mut Matrix) {
+    let n = a.row;
+    for m in 1..n.saturating_sub(1) {
+        let mut x = 0f64;
+        let mut i = m;
+        for j in m..n {
+            if a[(j, m - 1)].abs() > x.abs() {
+                x = a[(j, m - 1)];
+                i = j;
+            }
+        }
+        if i != m {
+            for j in m - 1..n {
+                let t = a[(i, j)];
+                a[(i, j)] = a[(m, j)];
+                a[(m, j)] = t;
+            }
+            for j in 0..n {
+                let t = a[(j, i)];
+                a[(j, i)] = a[(j, m)];
+                a[(j, m)] = t;
+            }
+        }
+        if x != 0f64 {
+            for i in m + 1..n {
+                let mut y = a[(i, m - 1)];
+                if y != 0f64 {
+                    y /= x;
+                    a[(i, m - 1)] = y;
+                    for j in m..n {
+                        a[(i, j)] -= y * a[(m, j)];
+                    }
+                    for j in 0..n {
+                        a[(j, m)] += y * a[(j, i)];
+                    }
+                }
+            }
+        }
+    }
+    for i in 2..n {
+        for j in 0..i - 1 {
+            a[(i, j)] = 0f64;
+        }
+    }
+}
+
+/// Eigenvalues `(re, im)` of an upper Hessenberg matrix by the shifted QR method
+///
+/// `a` is destroyed. Returns `None` after 30 iterations without convergence for one eigenvalue.
+///
+/// * Reference : Press, William H., and William T. Vetterling. *Numerical Recipes.* Cambridge: Cambridge Univ. Press, 2007.
+fn hqr(a: &mut Matrix) -> Option<Vec<(f64, f64)>> {
+    let n = a.row;
+    let mut wr = vec![0f64; n];
+    let mut wi = vec![0f64; n];
+    let sign = |x: f64, y: f64| if y >= 0f64 { x.abs() } else { -x.abs() };
+
+    let mut anorm = 0f64;
+    for i in 0..n {
+        for j in i.saturating_sub(1)..n {
+            anorm += a[(i, j)].abs();
+        }
+    }
+    let mut nn = n as isize - 1;
+    let mut t = 0f64;
+    while nn >= 0 {
+        let mut its = 0;
+        loop {
+            // Look for a single small subdiagonal element
+            let mut l = nn;
+            while l >= 1 {
+                let k = l as usize;
+                let mut s = a[(k - 1, k - 1)].abs() + a[(k, k)].abs();
+                if s == 0f64 {
+                    s = anorm;
+                }
+                if a[(k, k - 1)].abs() + s == s {
+                    a[(k, k - 1)] = 0f64;
+                    break;
+                }
+                l -= 1;
+            }
+            let nu = nn as usize;
+            let mut x = a[(nu, nu)];
+            if l == nn {
+                // One root found
+                wr[nu] = x + t;
+                wi[nu] = 0f64;
+                nn -= 1;
+                break;
+            }
+            let mut y = a[(nu - 1, nu - 1)];
+            let mut w = a[(nu, nu - 1)] * a[(nu - 1, nu)];
+            if l == nn - 1 {
+                // Two roots found
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0f64 {
+                    let z = p + sign(z, p);
+                    wr[nu - 1] = x + z;
+                    wr[nu] = if z != 0f64 { x - w / z } else { x + z };
+                    wi[nu - 1] = 0f64;
+                    wi[nu] = 0f64;
+                } else {
+                    wr[nu - 1] = x + p;
+                    wr[nu] = x + p;
+                    wi[nu - 1] = -z;
+                    wi[nu] = z;
+                }
+                nn -= 2;
+                break;
+            }
+            if its == 30 {
+                return None;
+            }
+            if its == 10 || its == 20 {
+                // Exceptional shift
+                t += x;
+                for i in 0..=nu {
+                    a[(i, i)] -= x;
+                }
+                let s = a[(nu, nu - 1)].abs() + a[(nu - 1, nu - 2)].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            // Form shift & look for two consecutive small subdiagonal elements
+            let l = l as usize;
+            let mut m = nu - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[(m, m)];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[(m + 1, m)] + a[(m, m + 1)];
+                q = a[(m + 1, m + 1)] - z - rr - ss;
+                r = a[(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[(m, m - 1)].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[(m - 1, m - 1)].abs() + z.abs() + a[(m + 1, m + 1)].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=nu {
+                a[(i, i - 2)] = 0f64;
+                if i != m + 2 {
+                    a[(i, i - 3)] = 0f64;
+                }
+            }
+
+            // Double QR step on rows l..=nn & columns m..=nn
+            for k in m..nu {
+                if k != m {
+                    p = a[(k, k - 1)];
+                    q = a[(k + 1, k - 1)];
+                    r = if k != nu - 1 { a[(k + 2, k - 1)] } else { 0f64 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0f64 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s != 0f64 {
+                    if k == m {
+                        if l != m {
+                            a[(k, k - 1)] = -a[(k, k - 1)];
+                        }
+                    } else {
+                        a[(k, k - 1)] = -s * x;
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q /= p;
+                    r /= p;
+                    for j in k..=nu {
+                        p = a[(k, j)] + q * a[(k + 1, j)];
+                        if k != nu - 1 {
+                            p += r * a[(k + 2, j)];
+                            a[(k + 2, j)] -= p * z;
+                        }
+                        a[(k + 1, j)] -= p * y;
+                        a[(k, j)] -= p * x;
+                    }
+                    let mmin = if nu < k + 3 { nu } else { k + 3 };
+                    for i in l..=mmin {
+                        p = x * a[(i, k)] + y * a[(i, k + 1)];
+                        if k != nu - 1 {
+                            p += z * a[(i, k + 2)];
+                            a[(i, k + 2)] -= p * r;
+                        }
+                        a[(i, k + 1)] -= p * q;
+                        a[(i, k)] -= p;
+                    }
+                }
+            }
+        }
+    }
+    Some(wr.into_iter().zip(wi).collect())
+}
+
+// =============================================================================
+// Lanczos Method
+// =============================================================================
+/// Extreme eigenpairs of a symmetric linear operator by the Lanczos method
+///
+/// # Description
+/// Builds an orthonormal Krylov basis $Q$ of dimension `min(iters, dim)`
+/// (with full reorthogonalization) from a random starting vector
+/// (drawn from `global_rng`, see `set_global_seed`) such that $Q^T A Q = T$ is tridiagonal,
+/// and solves the small eigenproblem of $T$ by the Jacobi method.
+///
+/// * `a` : symmetric operator (e.g. `Matrix` or `SPMatrix`)
+/// * `dim` : dimension of `a`. `LinearOp` only provides `apply`,
+///   so the size of the starting vector has to be given explicitly.
+/// * `tol` : a Ritz pair $(\theta, y)$ is converged if $\lVert Ay - \theta y \rVert \leq$ `tol` $\cdot \max |\theta|$
+/// * `which` : `LargestAlgebraic` or `SmallestAlgebraic` end of the spectrum
+/// * Returns the `k` Ritz values of the chosen end (outermost first)
+///   and the corresponding Ritz vectors as columns of a `dim x k` matrix.
+/// * Returns `LanczosError::NotConverge` if fewer than `k` of them are converged
+///   (too few `iters`, or an invariant subspace of dimension below `k`).
+///
+/// Extreme eigenvalues converge first, so `iters` can be much smaller than `dim`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = ml_matrix("4 1 0 0;1 3 1 0;0 1 2 1;0 0 1 1");
+/// let dense = eigen(&a, Jacobi).eigenvalue; // descending order
+///
+/// let (values, vectors) = lanczos(&a, 4, 1, 4, 1e-10, LanczosWhich::LargestAlgebraic).unwrap();
+/// assert!((values[0] - dense[0]).abs() < 1e-10);
+/// let v = vectors.col(0);
+/// assert!(eq_vec(&(&a * &v), &v.fmap(|t| t * values[0]), 1e-8));
+///
+/// let (values, _) = lanczos(&a, 4, 1, 4, 1e-10, LanczosWhich::SmallestAlgebraic).unwrap();
+/// assert!((values[0] - dense[3]).abs() < 1e-10);
+///
+/// // Identity has a one-dimensional Krylov space
+/// assert!(lanczos(&eye(4), 4, 2, 4, 1e-10, LanczosWhich::LargestAlgebraic).is_err());
+/// ```
+pub fn lanczos<L: LinearOp<Vec<f64>, Vec<f64>>>(
+    a: &L,
+    dim: usize,
+    k: usize,
+    iters: usize,
+    tol: f64,
+    which: LanczosWhich,
+) -> Result<(Vec<f64>, Matrix)> {
+    let m = iters.min(dim);
+    assert!(k > 0 && k <= m, "k should be in 1..=min(iters, dim)");
+
+    let mut q = vec![random_unit_vector(dim)];
+    let mut alpha: Vec<f64> = Vec::with_capacity(m);
+    let mut beta: Vec<f64> = Vec::with_capacity(m);
+    let mut scale = 0f64;
+    // Norm of the next Lanczos vector, so that the residual of Ritz pair i is |b_last * s_{p-1,i}|
+    let b_last = loop {
+        let mut w = a.apply(&q[q.len() - 1]);
+        alpha.push(w.dot(&q[q.len() - 1]));
+        // Full reorthogonalization (twice is enough)
+        for _ in 0..2 {
+            for qi in q.iter() {
+                let c = w.dot(qi);
+                w.iter_mut().zip(qi.iter()).for_each(|(x, y)| *x -= c * y);
+            }
+        }
+        let b = w.norm(Norm::L2);
+        if q.len() == m {
+            break b;
+        }
+        scale = scale.max(alpha[alpha.len() - 1].abs()).max(b);
+        if b <= 1e-12 * scale {
+            // Invariant subspace
+            break 0f64;
+        }
+        beta.push(b);
+        q.push(w.into_iter().map(|x| x / b).collect());
+    };
+
+    let p = alpha.len();
+    let mut t = zeros(p, p);
+    for i in 0..p {
+        t[(i, i)] = alpha[i];
+        if i + 1 < p {
+            t[(i, i + 1)] = beta[i];
+            t[(i + 1, i)] = beta[i];
+        }
+    }
+    let (theta, s) = eigen(&t, Jacobi).extract();
+    let mut idx = (0..p).collect::<Vec<usize>>();
+    match which {
+        LanczosWhich::LargestAlgebraic => idx.sort_by(|&i, &j| theta[j].partial_cmp(&theta[i]).unwrap()),
+        LanczosWhich::SmallestAlgebraic => idx.sort_by(|&i, &j| theta[i].partial_cmp(&theta[j]).unwrap()),
+    }
+    idx.truncate(k);
+    let theta_max = theta.iter().fold(0f64, |acc, t| acc.max(t.abs()));
+    let converged = idx
+        .iter()
+        .filter(|&&i| (b_last * s[(p - 1, i)]).abs() <= tol * theta_max)
+        .count();
+    if converged < k {
+        bail!(LanczosError::NotConverge(converged, k));
+    }
+
+    let values = idx.iter().map(|&i| theta[i]).collect::<Vec<f64>>();
+    let mut data = vec![0f64; dim * idx.len()];
+    for (c, &i) in idx.iter().enumerate() {
+        let col = &mut data[c * dim..(c + 1) * dim];
+        for (j, qj) in q.iter().enumerate() {
+            let sji = s[(j, i)];
+            col.iter_mut().zip(qj.iter()).for_each(|(x, y)| *x += sji * y);
+        }
+    }
+    Ok((values, matrix(data, dim, idx.len(), Shape::Col)))
+}
@@ -1,11 +1,21 @@
 //! Differential equations & Numerical Analysis tools
 
 pub mod eigen;
+#[cfg(feature = "std")]
 pub mod integral;
+#[cfg(feature = "std")]
 pub mod interp;
+#[cfg(feature = "std")]
 pub mod newton;
+#[cfg(feature = "std")]
 pub mod ode;
+#[cfg(feature = "std")]
 pub mod optimize;
+#[cfg(feature = "std")]
 pub mod root;
+#[cfg(feature = "std")]
+pub mod signal;
+#[cfg(feature = "std")]
 pub mod spline;
+#[cfg(feature = "std")]
 pub mod utils;
\ No newline at end of file
@@ -33,6 +33,19 @@
 //!
 //! You can implement your own ODE solver by implementing the `ODESolver` trait.
 //!
+//! ## Checkpoint & resume
+//!
+//! - `ODEState`: Time, state vector and next step size of an integration.
+//!   - `checkpoint` serializes it to bytes, `from_checkpoint` restores it.
+//!   - `ODECheckpointError`: Error for invalid checkpoint buffers.
+//! - `ODESolver::solve_from`: Integrate from a given state and return the final state.
+//! - `ODESolver::resume`: Continue integration from a checkpoint buffer.
+//!
+//! All integrators are one-step methods, so an `ODEState` is the full state of `BasicODESolver`
+//! and a resumed run reproduces the uninterrupted one exactly.
+//! Custom solvers get default `solve_from` & `resume` built on `solve`
+//! (see `ODESolver::solve_from` for the caveat).
+//!
 //! ## Example
 //!
 //! ```rust
@@ -88,6 +101,7 @@
 //! ```
 
 use anyhow::{Result, bail};
+use std::convert::TryInto;
 
 /// Trait for defining an ODE problem.
 ///
@@ -171,11 +185,111 @@ impl std::fmt::Display for ODEError {
     }
 }
 
+/// Error for decoding `ODEState::checkpoint` buffers
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ODECheckpointError {
+    TooShort,
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    LengthMismatch(usize, usize), // expected, found
+}
+
+impl std::fmt::Display for ODECheckpointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ODECheckpointError::TooShort => write!(f, "Buffer is too short to contain a checkpoint header"),
+            ODECheckpointError::InvalidMagic => write!(f, "Invalid magic number for ODE checkpoint"),
+            ODECheckpointError::UnsupportedVersion(v) => write!(f, "Unsupported ODE checkpoint version: {}", v),
+            ODECheckpointError::LengthMismatch(e, l) => write!(f, "Expected {} bytes of state, but found {}", e, l),
+        }
+    }
+}
+
+impl std::error::Error for ODECheckpointError {}
+
 /// Trait for ODE solvers.
 ///
 /// Implement this trait to define your own ODE solver.
 pub trait ODESolver {
     fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)>;
+
+    /// Integrate from `state` until `t >= t_end`
+    ///
+    /// Returns the trajectory (starting at `state`) and the final state,
+    /// which can be passed to `solve_from` again or saved by `ODEState::checkpoint`.
+    ///
+    /// # Caution
+    ///
+    /// The default implementation calls `solve` with `state.y` as initial conditions
+    /// and keeps `state.dt` as the step size of the final state, because `solve` does not
+    /// expose the adapted step size. Solvers with internal state (e.g. adaptive step size
+    /// or multistep history) should override it to resume exactly.
+    fn solve_from<P: ODEProblem>(&self, problem: &P, state: ODEState, t_end: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        let ODEState { t, y, dt } = state;
+        let (t_vec, y_vec) = self.solve(&StartFrom { problem, y }, (t, t_end), dt)?;
+        let state = ODEState {
+            t: *t_vec.last().unwrap(),
+            y: y_vec.last().unwrap().clone(),
+            dt,
+        };
+        Ok((t_vec, y_vec, state))
+    }
+
+    /// Resume integration from a buffer of `ODEState::checkpoint` until `t >= t_end`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     let solver = BasicODESolver::new(RK4);
+    ///     let (_, y_full) = solver.solve(&Decay, (0f64, 2f64), 0.01)?;
+    ///
+    ///     // Pause halfway & save
+    ///     let init = ODEState { t: 0f64, y: Decay.initial_conditions(), dt: 0.01 };
+    ///     let (_, _, state) = solver.solve_from(&Decay, init, 1f64)?;
+    ///     let buffer = state.checkpoint();
+    ///
+    ///     // Restart later
+    ///     let (_, y_rest, _) = solver.resume(&buffer, &Decay, 2f64)?;
+    ///     assert_eq!(y_rest.last(), y_full.last());
+    ///     Ok(())
+    /// }
+    ///
+    /// struct Decay;
+    ///
+    /// impl ODEProblem for Decay {
+    ///     fn initial_conditions(&self) -> Vec<f64> {
+    ///         vec![1f64]
+    ///     }
+    ///
+    ///     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+    ///         dy[0] = -y[0];
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    fn resume<P: ODEProblem>(&self, checkpoint: &[u8], problem: &P, t_end: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        let state = ODEState::from_checkpoint(checkpoint)?;
+        self.solve_from(problem, state, t_end)
+    }
+}
+
+/// Problem with initial conditions replaced (for default `ODESolver::solve_from`)
+struct StartFrom<'a, P: ODEProblem> {
+    problem: &'a P,
+    y: Vec<f64>,
+}
+
+impl<'a, P: ODEProblem> ODEProblem for StartFrom<'a, P> {
+    fn initial_conditions(&self) -> Vec<f64> {
+        self.y.clone()
+    }
+
+    fn rhs(&self, t: f64, y: &[f64], dy: &mut [f64]) -> Result<()> {
+        self.problem.rhs(t, y, dy)
+    }
 }
 
 /// A basic ODE solver using a specified integrator.
@@ -223,13 +337,21 @@ impl<I: ODEIntegrator> BasicODESolver<I> {
 
 impl<I: ODEIntegrator> ODESolver for BasicODESolver<I> {
     fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
-        let mut t = t_span.0;
-        let mut dt = dt;
-        let mut y = problem.initial_conditions();
+        let state = ODEState {
+            t: t_span.0,
+            y: problem.initial_conditions(),
+            dt,
+        };
+        let (t_vec, y_vec, _) = self.solve_from(problem, state, t_span.1)?;
+        Ok((t_vec, y_vec))
+    }
+
+    fn solve_from<P: ODEProblem>(&self, problem: &P, state: ODEState, t_end: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>, ODEState)> {
+        let ODEState { mut t, mut y, mut dt } = state;
         let mut t_vec = vec![t];
         let mut y_vec = vec![y.clone()];
 
-        while t < t_span.1 {
+        while t < t_end {
             let dt_step = self.integrator.step(problem, t, &mut y, dt)?;
             t += dt;
             t_vec.push(t);
@@ -237,7 +359,73 @@ impl<I: ODEIntegrator> ODESolver for BasicODESolver<I> {
             dt = dt_step;
         }
 
-        Ok((t_vec, y_vec))
+        Ok((t_vec, y_vec, ODEState { t, y, dt }))
+    }
+}
+
+/// State of an ODE integration (time, state vector & next step size)
+///
+/// # Binary format
+///
+/// `checkpoint` writes (little-endian):
+///
+/// | Field   | Size          |
+/// | :-----: | :-----------: |
+/// | magic   | 4 (`b"PRXO"`) |
+/// | version | 1             |
+/// | t       | 8 (`f64`)     |
+/// | dt      | 8 (`f64`)     |
+/// | len     | 8 (`u64`)     |
+/// | y       | 8 * len       |
+#[derive(Debug, Clone, PartialEq)]
+pub struct ODEState {
+    pub t: f64,
+    pub y: Vec<f64>,
+    pub dt: f64,
+}
+
+const CHECKPOINT_MAGIC: &[u8; 4] = b"PRXO";
+const CHECKPOINT_VERSION: u8 = 1;
+const CHECKPOINT_HEADER: usize = 29;
+
+impl ODEState {
+    /// Serialize state to bytes
+    pub fn checkpoint(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CHECKPOINT_HEADER + 8 * self.y.len());
+        bytes.extend_from_slice(CHECKPOINT_MAGIC);
+        bytes.push(CHECKPOINT_VERSION);
+        bytes.extend_from_slice(&self.t.to_le_bytes());
+        bytes.extend_from_slice(&self.dt.to_le_bytes());
+        bytes.extend_from_slice(&(self.y.len() as u64).to_le_bytes());
+        for x in self.y.iter() {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserialize state from bytes of `checkpoint`
+    pub fn from_checkpoint(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < CHECKPOINT_HEADER {
+            bail!(ODECheckpointError::TooShort);
+        }
+        if &bytes[0..4] != CHECKPOINT_MAGIC {
+            bail!(ODECheckpointError::InvalidMagic);
+        }
+        if bytes[4] != CHECKPOINT_VERSION {
+            bail!(ODECheckpointError::UnsupportedVersion(bytes[4]));
+        }
+        let read_f64 = |k: usize| f64::from_le_bytes(bytes[k..k + 8].try_into().unwrap());
+        let t = read_f64(5);
+        let dt = read_f64(13);
+        let len = u64::from_le_bytes(bytes[21..29].try_into().unwrap()) as usize;
+        let found = bytes.len() - CHECKPOINT_HEADER;
+        match len.checked_mul(8) {
+            Some(expected) if expected == found => (),
+            Some(expected) => bail!(ODECheckpointError::LengthMismatch(expected, found)),
+            None => bail!(ODECheckpointError::LengthMismatch(usize::MAX, found)),
+        }
+        let y = (0..len).map(|i| read_f64(CHECKPOINT_HEADER + 8 * i)).collect();
+        Ok(ODEState { t, y, dt })
     }
 }
 
@@ -8,6 +8,7 @@
 //! extern crate peroxide;
 //! use peroxide::fuga::*;
 //! use std::collections::HashMap;
+//! use std::time::Duration;
 //!
 //! pub struct Optimizer<F>
 //! where F: Fn(&Vec<f64>, Vec<AD>) -> Option<Vec<AD>> {
@@ -20,6 +21,8 @@
 //!     method: OptMethod,
 //!     option: HashMap<OptOption, bool>,
 //!     hyperparams: HashMap<String, f64>,
+//!     time_budget: Option<Duration>,
+//!     status: OptStatus,
 //! }
 //! ```
 //!
@@ -35,13 +38,16 @@
 //! * `get_domain` : Get domain
 //! * `get_error` : Root mean square error
 //! * `get_hyperparam` : Get hyperparameter
+//! * `get_status` : Why the last optimization stopped (`Finished` or `TimedOut`)
 //! * `set_lr` : Set learning rate (For `GradientDescent`)
 //! * `set_lambda_init` : Set initial value of lambda (For `LevenbergMarquardt`)
 //! * `set_lambda_max` : Set maximum value of lambda (For `LevenbergMarquardt`)
+//! * `set_time_budget` : Set wall-clock time budget
 //!
 //! ### Method (Generate result)
 //!
 //! * `optimize` : Optimize
+//! * `optimize_with_callback` : Optimize with a progress callback `(iteration, chi2)`
 //!
 //! ## Example
 //!
@@ -115,6 +121,7 @@ use crate::structure::matrix::{LinearAlgebra, Matrix};
 use crate::structure::ad::{AD, ADVec};
 use crate::util::useful::max;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy)]
 pub enum OptMethod {
@@ -129,6 +136,16 @@ pub enum OptOption {
     MaxIter,
 }
 
+/// Termination status of optimization
+///
+/// * `Finished` : Stopped by maximum iteration or convergence criteria
+/// * `TimedOut` : Stopped because the time budget was exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptStatus {
+    Finished,
+    TimedOut,
+}
+
 /// Optimizer for optimization (non-linear regression)
 ///
 /// # Methods
@@ -151,6 +168,8 @@ where
     method: OptMethod,
     option: HashMap<OptOption, bool>,
     hyperparams: HashMap<String, f64>,
+    time_budget: Option<Duration>,
+    status: OptStatus,
 }
 
 impl<F> Optimizer<F>
@@ -172,6 +191,8 @@ where
             method: LevenbergMarquardt,
             option: default_option,
             hyperparams: HashMap::new(),
+            time_budget: None,
+            status: OptStatus::Finished,
         }
     }
 
@@ -190,6 +211,11 @@ where
         self.hyperparams.get(key)
     }
 
+    /// Get termination status of the last optimization
+    pub fn get_status(&self) -> OptStatus {
+        self.status
+    }
+
     /// Set initial parameter
     pub fn set_init_param(&mut self, p: Vec<f64>) -> &mut Self {
         if let Some(x) = self.option.get_mut(&InitParam) {
@@ -234,8 +260,81 @@ where
         self
     }
 
+    /// Set wall-clock time budget (`None` means unlimited)
+    ///
+    /// If the budget is exceeded, the parameter with the lowest sum of squared residuals
+    /// found so far is returned and `get_status` becomes `OptStatus::TimedOut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use peroxide::{fuga::*, hstack};
+    /// use std::time::Duration;
+    ///
+    /// let x = seq(0, 10, 1);
+    /// let y = x.fmap(|t| 2f64 * t);
+    /// let data = hstack!(x, y);
+    ///
+    /// let mut opt = Optimizer::new(data, |x: &Vec<f64>, p: Vec<AD>| {
+    ///     Some(x.iter().map(|t| p[0] * *t).collect())
+    /// });
+    /// opt.set_init_param(vec![1f64])
+    ///     .set_max_iter(usize::MAX)
+    ///     .set_method(GradientDescent)
+    ///     .set_lr(1e-3)
+    ///     .set_time_budget(Some(Duration::from_millis(10)))
+    ///     .optimize();
+    ///
+    /// assert_eq!(opt.get_status(), OptStatus::TimedOut);
+    /// ```
+    pub fn set_time_budget(&mut self, budget: Option<Duration>) -> &mut Self {
+        self.time_budget = budget;
+        self
+    }
+
     /// Main function for optimization
     pub fn optimize(&mut self) -> Vec<f64> {
+        self.optimize_with_callback(None)
+    }
+
+    /// Optimization with progress callback
+    ///
+    /// # Description
+    ///
+    /// `on_iteration` is called at the end of every completed iteration with
+    /// the iteration index and the current sum of squared residuals.
+    /// Iterations that only move to a parameter where the model cannot be evaluated
+    /// (and retry from there) are skipped, since no residual is available for them.
+    /// If `None`, the residual is not computed at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use peroxide::{fuga::*, hstack};
+    ///
+    /// let x = seq(0, 10, 1);
+    /// let y = x.fmap(|t| 2f64 * t);
+    /// let data = hstack!(x, y);
+    ///
+    /// let mut history = vec![];
+    /// let mut log = |_i: usize, chi2: f64| history.push(chi2);
+    ///
+    /// let mut opt = Optimizer::new(data, |x: &Vec<f64>, p: Vec<AD>| {
+    ///     Some(x.iter().map(|t| p[0] * *t).collect())
+    /// });
+    /// opt.set_init_param(vec![1f64])
+    ///     .set_max_iter(10)
+    ///     .set_method(GradientDescent)
+    ///     .set_lr(1e-3)
+    ///     .optimize_with_callback(Some(&mut log));
+    ///
+    /// assert_eq!(history.len(), 10);
+    /// assert!(history[9] < history[0]);
+    /// ```
+    pub fn optimize_with_callback(
+        &mut self,
+        mut on_iteration: Option<&mut dyn FnMut(usize, f64)>,
+    ) -> Vec<f64> {
         // Receive initial data
         let (x_vec, y_vec) = (self.domain.clone(), self.observed.clone());
         let (p_init, max_iter) = (self.param.clone(), self.max_iter);
@@ -253,11 +352,27 @@ where
         let mut jtj = &j.t() * &j;
         let mut valid_p = p.clone();
         let mut err_stack = 0usize;
+        let chi2_of = |y_hat: &Matrix| {
+            let r = &y - y_hat;
+            (r.t() * r)[(0, 0)]
+        };
+        // Lowest sum of squared residuals so far with its parameters & predictions
+        let mut best = (chi2_of(&y_hat), p.clone(), y_hat.clone());
+        let time_budget = self.time_budget;
+        let start = Instant::now();
+        let timed_out = || matches!(time_budget, Some(budget) if start.elapsed() > budget);
+        let mut status = OptStatus::Finished;
 
         match self.method {
             GradientDescent => {
                 let alpha = *self.hyperparams.get("lr").unwrap_or(&1e-3);
                 for i in 0..max_iter {
+                    if timed_out() {
+                        p = best.1;
+                        y_hat = best.2;
+                        status = OptStatus::TimedOut;
+                        break;
+                    }
                     let h = alpha * j.t() * (&y - &y_hat);
                     let p_cand = &p + &h;
                     match unsafe_f(p_cand.data.to_ad_vec()) {
@@ -267,24 +382,32 @@ where
                             err_stack = 0;
                             j = jacobian(safe_f, &p.data);
                             y_hat = value.to_f64_vec().into();
+                            let chi2 = chi2_of(&y_hat);
+                            if chi2 < best.0 {
+                                best = (chi2, p.clone(), y_hat.clone());
+                            }
                         }
                         None => {
                             if i < max_iter - 1 && err_stack < 3 {
                                 p = p_cand;
                                 err_stack += 1;
+                                continue;
                             } else {
                                 p = valid_p;
                                 break;
                             }
                         }
                     }
+                    if let Some(callback) = on_iteration.as_mut() {
+                        callback(i, chi2_of(&y_hat));
+                    }
                 }
             }
 
             GaussNewton => unimplemented!(),
 
             LevenbergMarquardt => {
-                let mut chi2 = ((&y - &y_hat).t() * (&y - &y_hat))[(0, 0)];
+                let mut chi2 = best.0;
                 let mut nu = 2f64;
                 let lambda_0 = *self.hyperparams.get("lambda_init").unwrap_or(&1e-3);
                 let lambda_max = *self.hyperparams.get("lambda_max").unwrap_or(&f64::MAX.sqrt());
@@ -292,6 +415,12 @@ where
                 let mut lambda = lambda_0 * max(jtj.diag());
 
                 for i in 0..max_iter {
+                    if timed_out() {
+                        p = best.1;
+                        y_hat = best.2;
+                        status = OptStatus::TimedOut;
+                        break;
+                    }
                     if lambda > lambda_max {
                         println!("Caution: At {}-th iter, lambda exceeds max value: {}", i+1, lambda);
                         break;
@@ -324,6 +453,9 @@ where
                                 jtj = &j.t() * &j;
                                 y_hat = y_hat_temp;
                                 chi2 = chi2_temp;
+                                if chi2 < best.0 {
+                                    best = (chi2, p.clone(), y_hat.clone());
+                                }
                                 lambda *=
                                     max(vec![1f64 / 3f64, 1f64 - (2f64 * rho - 1f64).powi(3)]);
                                 nu = 2f64;
@@ -336,15 +468,20 @@ where
                             if i < max_iter - 1 && err_stack < 3 {
                                 p = p_temp;
                                 err_stack += 1;
+                                continue;
                             } else {
                                 p = valid_p;
                                 break;
                             }
                         }
                     }
+                    if let Some(callback) = on_iteration.as_mut() {
+                        callback(i, chi2);
+                    }
                 }
             }
         }
+        self.status = status;
         let error_temp = &y - &y_hat;
         self.error = ((error_temp.t() * error_temp)[(0, 0)] / y.row as f64).sqrt();
         p.data
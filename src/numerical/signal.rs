@@ -0,0 +1,682 @@
+//! Signal processing tools
+//!
+//! ## Windows
+//!
+//! * `get_window(kind, n)` : Symmetric window of length `n` (`WindowKind`)
+//!
+//! ## Filters
+//!
+//! * `firwin(numtaps, cutoff, window)` : Windowed-sinc FIR low-pass design
+//! * `filter(b, x)` : Apply FIR filter
+//! * `lfilter(b, a, x)` : Apply IIR filter (direct form)
+//! * `butter(order, cutoff, kind)` : Butterworth IIR design (`FilterKind`, `complex` feature)
+//! * `filtfilt(b, a, x)` : Zero-phase filtering (forward & backward)
+//!
+//! ## Peak detection
+//!
+//! * `find_peaks(x, min_height, min_distance)` : Indices of local maxima
+//!
+//! ## Rate conversion
+//!
+//! * `resample(x, up, down)` : Polyphase resampling with anti-aliasing low-pass filter
+//!
+//! ## Time-frequency analysis (`complex` feature)
+//!
+//! * `stft`, `istft` : Short-time Fourier transform and its inverse (weighted overlap-add)
+//! * `hilbert` : Analytic signal (`envelope`, `instantaneous_phase`)
+//!
+//! ## Linear prediction
+//!
+//! * `levinson_durbin` : Solve Yule-Walker equations (Toeplitz system) for AR coefficients
+
+#[cfg(feature = "complex")]
+use crate::complex::{
+    fft::{fft, ifft},
+    C64,
+};
+use std::f64::consts::PI;
+
+/// Kinds of window
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WindowKind {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+/// Symmetric window of length `n`
+///
+/// # Description
+/// $w_k = a_0 - a_1 \cos\left(\frac{2\pi k}{n-1}\right) + a_2 \cos\left(\frac{4\pi k}{n-1}\right)$
+///
+/// | Kind | $a_0$ | $a_1$ | $a_2$ |
+/// | :--- | :---: | :---: | :---: |
+/// | Rectangular | 1 | 0 | 0 |
+/// | Hann | 0.5 | 0.5 | 0 |
+/// | Hamming | 0.54 | 0.46 | 0 |
+/// | Blackman | 0.42 | 0.5 | 0.08 |
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let w = get_window(WindowKind::Hann, 5);
+/// assert!(eq_vec(&w, &vec![0f64, 0.5, 1f64, 0.5, 0f64], 1e-12));
+/// ```
+pub fn get_window(kind: WindowKind, n: usize) -> Vec<f64> {
+    if n <= 1 {
+        return vec![1f64; n];
+    }
+    let (a0, a1, a2) = match kind {
+        WindowKind::Rectangular => (1f64, 0f64, 0f64),
+        WindowKind::Hann => (0.5, 0.5, 0f64),
+        WindowKind::Hamming => (0.54, 0.46, 0f64),
+        WindowKind::Blackman => (0.42, 0.5, 0.08),
+    };
+    let m = (n - 1) as f64;
+    (0..n)
+        .map(|k| {
+            let x = 2f64 * PI * k as f64 / m;
+            a0 - a1 * x.cos() + a2 * (2f64 * x).cos()
+        })
+        .collect()
+}
+
+/// Resample by the rational factor `up / down`
+///
+/// # Description
+/// Polyphase implementation of: zero-stuffing by `up`, low-pass filtering, and decimation by `down`.
+/// The anti-aliasing filter is a Blackman-windowed sinc with cutoff at the lower of the two
+/// Nyquist frequencies and `20 * max(up, down) + 1` taps (after reducing `up / down`).
+/// The filter delay is compensated, so the output is aligned with the input.
+///
+/// The output has `ceil(x.len() * up / down)` samples.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// // 2 Hz sine sampled at 40 Hz -> 60 Hz
+/// let x = (0..200).map(|i| (2f64 * PI * 2f64 * i as f64 / 40f64).sin()).collect::<Vec<_>>();
+/// let y = resample(&x, 3, 2);
+/// assert_eq!(y.len(), 300);
+/// for i in 60..240 {
+///     let expected = (2f64 * PI * 2f64 * i as f64 / 60f64).sin();
+///     assert!((y[i] - expected).abs() < 1e-3);
+/// }
+/// ```
+pub fn resample(x: &[f64], up: usize, down: usize) -> Vec<f64> {
+    assert!(up > 0 && down > 0, "Resampling factors should be positive");
+    let g = gcd(up, down);
+    let (up, down) = (up / g, down / g);
+    if up == 1 && down == 1 {
+        return x.to_vec();
+    }
+    let n = x.len();
+    let out_len = (n * up).div_ceil(down);
+
+    let max_rate = up.max(down);
+    let half = 10 * max_rate;
+    let cutoff = 1f64 / max_rate as f64;
+    let h = firwin(2 * half + 1, cutoff, WindowKind::Blackman)
+        .into_iter()
+        .map(|t| t * up as f64)
+        .collect::<Vec<f64>>();
+
+    (0..out_len)
+        .map(|m| {
+            // position in the zero-stuffed signal (delay compensated)
+            let t = m * down + half;
+            let mut s = 0f64;
+            let mut k = t % up;
+            while k < h.len() && k <= t {
+                let j = (t - k) / up;
+                if j < n {
+                    s += h[k] * x[j];
+                }
+                k += up;
+            }
+            s
+        })
+        .collect()
+}
+
+/// FIR low-pass filter design by the window method
+///
+/// # Description
+/// Windowed ideal low-pass (sinc) filter with `numtaps` coefficients.
+/// `cutoff` is relative to the Nyquist frequency (`0 < cutoff < 1`),
+/// and the coefficients are normalized to unit gain at DC.
+/// The filter is linear-phase with delay `(numtaps - 1) / 2` samples.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let h = firwin(31, 0.25, WindowKind::Hamming);
+/// assert_eq!(h.len(), 31);
+/// assert!((h.iter().sum::<f64>() - 1f64).abs() < 1e-12);
+/// assert!((h[0] - h[30]).abs() < 1e-15); // symmetric
+/// ```
+pub fn firwin(numtaps: usize, cutoff: f64, window: WindowKind) -> Vec<f64> {
+    assert!(numtaps > 0, "Number of taps should be positive");
+    assert!(
+        cutoff > 0f64 && cutoff < 1f64,
+        "Cutoff should be in (0, 1) relative to Nyquist"
+    );
+    let w = get_window(window, numtaps);
+    let c = (numtaps - 1) as f64 / 2f64;
+    let h = (0..numtaps)
+        .map(|k| {
+            let t = k as f64 - c;
+            let sinc = if t == 0f64 {
+                1f64
+            } else {
+                (PI * cutoff * t).sin() / (PI * cutoff * t)
+            };
+            cutoff * sinc * w[k]
+        })
+        .collect::<Vec<f64>>();
+    let s: f64 = h.iter().sum();
+    h.into_iter().map(|t| t / s).collect()
+}
+
+/// Apply FIR filter
+///
+/// # Description
+/// Causal convolution $y_n = \sum_k b_k x_{n-k}$ (zero initial state),
+/// truncated to the length of `x`.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let y = filter(&[0.5, 0.5], &[2f64, 4f64, 6f64]);
+/// assert_eq!(y, vec![1f64, 3f64, 5f64]);
+/// ```
+pub fn filter(b: &[f64], x: &[f64]) -> Vec<f64> {
+    (0..x.len())
+        .map(|n| {
+            b.iter()
+                .take(n + 1)
+                .enumerate()
+                .map(|(k, bk)| bk * x[n - k])
+                .sum()
+        })
+        .collect()
+}
+
+/// Apply IIR filter (direct form)
+///
+/// # Description
+/// $a_0 y_n = \sum_k b_k x_{n-k} - \sum_{k \geq 1} a_k y_{n-k}$ (zero initial state).
+/// Coefficients are normalized by `a[0]`, and `lfilter(b, &[1.0], x)` is the same as `filter(b, x)`.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // y[n] = x[n] + 0.5 y[n-1]
+/// let y = lfilter(&[1f64], &[1f64, -0.5], &[1f64, 0f64, 0f64, 0f64]);
+/// assert_eq!(y, vec![1f64, 0.5, 0.25, 0.125]);
+/// ```
+pub fn lfilter(b: &[f64], a: &[f64], x: &[f64]) -> Vec<f64> {
+    assert!(!a.is_empty() && a[0] != 0f64, "a[0] should be nonzero");
+    let a0 = a[0];
+    let mut y = vec![0f64; x.len()];
+    for n in 0..x.len() {
+        let mut s: f64 = b
+            .iter()
+            .take(n + 1)
+            .enumerate()
+            .map(|(k, bk)| bk * x[n - k])
+            .sum();
+        for (k, ak) in a.iter().enumerate().take(n + 1).skip(1) {
+            s -= ak * y[n - k];
+        }
+        y[n] = s / a0;
+    }
+    y
+}
+
+/// Kinds of filter for `butter`
+///
+/// * `LowPass`, `HighPass` : single edge `cutoff`
+/// * `BandPass(high)` : pass band from `cutoff` to `high`
+#[cfg(feature = "complex")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FilterKind {
+    LowPass,
+    HighPass,
+    BandPass(f64),
+}
+
+/// Butterworth IIR filter design
+///
+/// # Description
+/// Analog Butterworth prototype, frequency transformation (low/high/band pass)
+/// and bilinear transform with pre-warping.
+/// Frequencies are relative to the Nyquist frequency (`0 < cutoff < 1`),
+/// and the magnitude response is $1/\sqrt{2}$ (-3 dB) at the edges.
+///
+/// Returns `(b, a)` for `lfilter` or `filtfilt`
+/// (length `order + 1`, or `2 * order + 1` for band pass).
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// # #[cfg(feature = "complex")]
+/// # {
+/// let (b, a) = butter(2, 0.5, FilterKind::LowPass);
+/// // 2nd order low pass at half Nyquist
+/// assert!(eq_vec(&b, &vec![0.2928932188134524, 0.5857864376269049, 0.2928932188134524], 1e-12));
+/// assert!(eq_vec(&a, &vec![1f64, 0f64, 0.1715728752538099], 1e-12));
+/// # }
+/// ```
+#[cfg(feature = "complex")]
+pub fn butter(order: usize, cutoff: f64, kind: FilterKind) -> (Vec<f64>, Vec<f64>) {
+    assert!(order > 0, "Order should be positive");
+    let check = |w: f64| {
+        assert!(
+            w > 0f64 && w < 1f64,
+            "Cutoff should be in (0, 1) relative to Nyquist"
+        )
+    };
+    check(cutoff);
+
+    // Analog prototype (cutoff 1 rad/s)
+    let n = order as f64;
+    let proto = (0..order)
+        .map(|k| C64::from_polar(1f64, PI * (2f64 * k as f64 + n + 1f64) / (2f64 * n)))
+        .collect::<Vec<C64>>();
+
+    // Pre-warp for the bilinear transform with fs = 2
+    let warp = |w: f64| 4f64 * (PI * w / 2f64).tan();
+    let (zeros, poles, gain) = match kind {
+        FilterKind::LowPass => {
+            let wo = warp(cutoff);
+            let p = proto.iter().map(|&p| p * wo).collect::<Vec<C64>>();
+            (vec![], p, wo.powi(order as i32))
+        }
+        FilterKind::HighPass => {
+            let wo = warp(cutoff);
+            let p = proto.iter().map(|&p| wo / p).collect::<Vec<C64>>();
+            let k = 1f64 / proto.iter().map(|&p| -p).product::<C64>().re;
+            (vec![C64::new(0f64, 0f64); order], p, k)
+        }
+        FilterKind::BandPass(high) => {
+            check(high);
+            assert!(high > cutoff, "Upper edge should be larger than cutoff");
+            let (wl, wh) = (warp(cutoff), warp(high));
+            let bw = wh - wl;
+            let wo2 = C64::new(wl * wh, 0f64);
+            let mut p = Vec::with_capacity(2 * order);
+            for &q in proto.iter() {
+                let q = q * (bw / 2f64);
+                let d = (q * q - wo2).sqrt();
+                p.push(q + d);
+                p.push(q - d);
+            }
+            (vec![C64::new(0f64, 0f64); order], p, bw.powi(order as i32))
+        }
+    };
+
+    // Bilinear transform (fs = 2)
+    let fs2 = C64::new(4f64, 0f64);
+    let zd = zeros
+        .iter()
+        .map(|&z| (fs2 + z) / (fs2 - z))
+        .chain(std::iter::repeat_n(
+            C64::new(-1f64, 0f64),
+            poles.len() - zeros.len(),
+        ))
+        .collect::<Vec<C64>>();
+    let pd = poles
+        .iter()
+        .map(|&p| (fs2 + p) / (fs2 - p))
+        .collect::<Vec<C64>>();
+    let k = gain
+        * (zeros.iter().map(|&z| fs2 - z).product::<C64>()
+            / poles.iter().map(|&p| fs2 - p).product::<C64>())
+        .re;
+
+    let b = poly(&zd).into_iter().map(|c| k * c.re).collect();
+    let a = poly(&pd).into_iter().map(|c| c.re).collect();
+    (b, a)
+}
+
+/// Zero-phase filtering
+///
+/// # Description
+/// Applies `lfilter` forward and backward, so the phase shift cancels
+/// and the magnitude response is squared.
+/// To reduce transients, `x` is extended at both ends by odd reflection
+/// of length `3 * max(a.len(), b.len())` (limited by `x.len() - 1`).
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+/// use std::f64::consts::PI;
+///
+/// # #[cfg(feature = "complex")]
+/// # {
+/// let x = (0..500).map(|i| (2f64 * PI * i as f64 / 100f64).sin()).collect::<Vec<_>>();
+/// let (b, a) = butter(4, 0.2, FilterKind::LowPass);
+/// let y = filtfilt(&b, &a, &x);
+/// for i in 100..400 {
+///     assert!((y[i] - x[i]).abs() < 1e-2); // no delay
+/// }
+/// # }
+/// ```
+pub fn filtfilt(b: &[f64], a: &[f64], x: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    if n == 0 {
+        return vec![];
+    }
+    let pad = (3 * a.len().max(b.len())).min(n - 1);
+    let mut ext = Vec::with_capacity(n + 2 * pad);
+    ext.extend((1..=pad).rev().map(|i| 2f64 * x[0] - x[i]));
+    ext.extend_from_slice(x);
+    ext.extend((1..=pad).map(|i| 2f64 * x[n - 1] - x[n - 1 - i]));
+
+    let mut y = lfilter(b, a, &ext);
+    y.reverse();
+    let mut y = lfilter(b, a, &y);
+    y.reverse();
+    y[pad..pad + n].to_vec()
+}
+
+/// Find local maxima
+///
+/// # Description
+/// Returns indices of samples larger than both neighbors (for flat peaks, the middle sample).
+/// End points are never peaks.
+///
+/// * `min_height` : Keep only peaks with `x[i] >= min_height`
+/// * `min_distance` : Keep peaks at least `min_distance` samples apart.
+///   Higher peaks take precedence over lower ones nearby.
+///
+/// The result is sorted in ascending order.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![0f64, 2f64, 0f64, 3f64, 0f64, 1f64, 1f64, 1f64, 0f64];
+/// assert_eq!(find_peaks(&x, None, None), vec![1, 3, 6]);
+/// assert_eq!(find_peaks(&x, Some(1.5), None), vec![1, 3]);
+/// assert_eq!(find_peaks(&x, None, Some(3)), vec![3, 6]);
+/// ```
+pub fn find_peaks(x: &[f64], min_height: Option<f64>, min_distance: Option<usize>) -> Vec<usize> {
+    let n = x.len();
+    let mut peaks = vec![];
+    let mut i = 1;
+    while i + 1 < n {
+        if x[i - 1] < x[i] {
+            // skip plateau
+            let mut j = i;
+            while j + 1 < n && x[j + 1] == x[i] {
+                j += 1;
+            }
+            if j + 1 < n && x[j + 1] < x[i] {
+                peaks.push((i + j) / 2);
+            }
+            i = j + 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    if let Some(h) = min_height {
+        peaks.retain(|&p| x[p] >= h);
+    }
+
+    if let Some(d) = min_distance {
+        if d > 1 {
+            let mut order = (0..peaks.len()).collect::<Vec<usize>>();
+            order.sort_by(|&a, &b| x[peaks[b]].partial_cmp(&x[peaks[a]]).unwrap());
+            let mut keep = vec![true; peaks.len()];
+            for &k in order.iter() {
+                if !keep[k] {
+                    continue;
+                }
+                let p = peaks[k];
+                for (l, &q) in peaks.iter().enumerate() {
+                    if l != k && q.abs_diff(p) < d {
+                        keep[l] = false;
+                    }
+                }
+            }
+            peaks = peaks
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(p, k)| if k { Some(p) } else { None })
+                .collect();
+        }
+    }
+    peaks
+}
+
+/// Coefficients of $\prod_k (z - r_k)$ in descending powers
+#[cfg(feature = "complex")]
+fn poly(roots: &[C64]) -> Vec<C64> {
+    let mut c = vec![C64::new(1f64, 0f64)];
+    for &r in roots {
+        let mut next = vec![C64::new(0f64, 0f64); c.len() + 1];
+        for (i, &ci) in c.iter().enumerate() {
+            next[i] += ci;
+            next[i + 1] -= ci * r;
+        }
+        c = next;
+    }
+    c
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Short-time Fourier transform
+///
+/// # Description
+/// The signal is cut into segments of length `window.len()` starting at `0, hop, 2*hop, ...`
+/// (only complete segments), and each windowed segment is transformed by `fft`.
+///
+/// Returns the spectrogram as a list of columns: `result[m][k]` is frequency bin `k`
+/// (full spectrum, `k / window.len()` cycles per sample) of frame `m`.
+/// Since there is no complex matrix type, columns are `Vec<C64>` instead of a `CMatrix`.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// # #[cfg(feature = "complex")]
+/// # {
+/// let x = (0..64).map(|t| (2f64 * std::f64::consts::PI * 0.25 * t as f64).sin()).collect::<Vec<_>>();
+/// let w = get_window(WindowKind::Hann, 16);
+/// let s = stft(&x, &w, 8);
+/// assert_eq!(s.len(), 7);
+/// // Peak at bin 4 = 0.25 * 16
+/// let mag = s[0][..8].iter().map(|z| z.norm_sqr()).collect::<Vec<_>>();
+/// assert_eq!(mag.arg_max(), 4);
+/// # }
+/// ```
+#[cfg(feature = "complex")]
+pub fn stft(signal: &[f64], window: &[f64], hop: usize) -> Vec<Vec<C64>> {
+    let n = window.len();
+    assert!(n > 0, "Window should not be empty");
+    assert!(hop > 0, "Hop size should be positive");
+    if signal.len() < n {
+        return vec![];
+    }
+    (0..=(signal.len() - n) / hop)
+        .map(|m| {
+            let seg = signal[m * hop..m * hop + n]
+                .iter()
+                .zip(window)
+                .map(|(x, w)| C64::new(x * w, 0f64))
+                .collect::<Vec<C64>>();
+            fft(&seg)
+        })
+        .collect()
+}
+
+/// Inverse short-time Fourier transform
+///
+/// # Description
+/// Weighted overlap-add: each frame is inverted by `ifft`, multiplied by `window` again,
+/// summed at its position and normalized by $\sum w^2$.
+/// The output has length `(frames.len() - 1) * hop + window.len()`.
+/// Samples where $\sum w^2 = 0$ (e.g. the end points of a Hann window) are set to zero.
+///
+/// For a window and hop such that every sample is covered, `istft(stft(x, w, hop), w, hop)`
+/// recovers `x`.
+#[cfg(feature = "complex")]
+pub fn istft(frames: &[Vec<C64>], window: &[f64], hop: usize) -> Vec<f64> {
+    let n = window.len();
+    assert!(hop > 0, "Hop size should be positive");
+    if frames.is_empty() {
+        return vec![];
+    }
+    let len = (frames.len() - 1) * hop + n;
+    let mut y = vec![0f64; len];
+    let mut norm = vec![0f64; len];
+    for (m, frame) in frames.iter().enumerate() {
+        assert_eq!(
+            frame.len(),
+            n,
+            "Frame length should be equal to window length"
+        );
+        let seg = ifft(frame);
+        for k in 0..n {
+            y[m * hop + k] += seg[k].re * window[k];
+            norm[m * hop + k] += window[k] * window[k];
+        }
+    }
+    y.iter()
+        .zip(norm.iter())
+        .map(|(&v, &w)| if w > 1e-12 { v / w } else { 0f64 })
+        .collect()
+}
+
+/// Analytic signal via Hilbert transform
+///
+/// # Description
+/// Computes $x_a = x + i\mathcal{H}[x]$ by the FFT method:
+/// negative frequencies are zeroed, positive frequencies are doubled
+/// (DC and Nyquist bins are kept).
+/// The signal is treated as periodic, so expect edge effects for non-periodic input.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// # #[cfg(feature = "complex")]
+/// # {
+/// let x = (0..8).map(|t| (std::f64::consts::PI * t as f64 / 2f64).cos()).collect::<Vec<_>>();
+/// let z = hilbert(&x);
+/// // Hilbert transform of cos is sin
+/// for (t, v) in z.iter().enumerate() {
+///     assert!((v.im - (std::f64::consts::PI * t as f64 / 2f64).sin()).abs() < 1e-12);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "complex")]
+pub fn hilbert(x: &[f64]) -> Vec<C64> {
+    let n = x.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut spec = crate::complex::fft::rfft(x);
+    // Positive frequencies: 1 ..= last_pos, Nyquist bin (n even): n / 2
+    let last_pos = (n - 1) / 2;
+    let first_neg = n / 2 + 1;
+    spec[1..=last_pos].iter_mut().for_each(|z| *z *= 2f64);
+    spec[first_neg..]
+        .iter_mut()
+        .for_each(|z| *z = C64::new(0f64, 0f64));
+    ifft(&spec)
+}
+
+/// Envelope (magnitude of analytic signal)
+#[cfg(feature = "complex")]
+pub fn envelope(x: &[f64]) -> Vec<f64> {
+    hilbert(x).iter().map(|z| z.norm_sqr().sqrt()).collect()
+}
+
+/// Instantaneous phase (unwrapped argument of analytic signal)
+///
+/// # Description
+/// Jumps larger than $\pi$ between consecutive samples are unwrapped by multiples of $2\pi$,
+/// so the phase of a sinusoid grows linearly.
+#[cfg(feature = "complex")]
+pub fn instantaneous_phase(x: &[f64]) -> Vec<f64> {
+    let mut phase = hilbert(x).iter().map(|z| z.arg()).collect::<Vec<f64>>();
+    let mut offset = 0f64;
+    for i in 1..phase.len() {
+        let raw = phase[i] + offset;
+        let d = raw - phase[i - 1];
+        if d > PI {
+            offset -= 2f64 * PI * ((d + PI) / (2f64 * PI)).floor();
+        } else if d < -PI {
+            offset += 2f64 * PI * ((-d + PI) / (2f64 * PI)).floor();
+        }
+        phase[i] += offset;
+    }
+    phase
+}
+
+/// Levinson-Durbin recursion
+///
+/// # Description
+/// Solves the Yule-Walker equations for an AR(`order`) process
+/// $x_t = \sum_{k=1}^{p} a_k x_{t-k} + \epsilon_t$ from the autocorrelation sequence
+/// `autocorr = [r_0, r_1, ..., r_p, ...]` in $O(p^2)$.
+///
+/// Returns `(a, e)` where `a = [a_1, ..., a_p]` and `e` is the prediction error variance.
+///
+/// # Panics
+/// * If `autocorr.len() <= order`
+/// * If `autocorr[0] <= 0`
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // AR(1) with a_1 = 0.5, unit noise variance: r_k = 0.5^k / (1 - 0.25)
+/// let r = vec![1f64 / 0.75, 0.5 / 0.75];
+/// let (a, e) = levinson_durbin(&r, 1);
+/// assert!((a[0] - 0.5).abs() < 1e-12);
+/// assert!((e - 1f64).abs() < 1e-12);
+/// ```
+pub fn levinson_durbin(autocorr: &[f64], order: usize) -> (Vec<f64>, f64) {
+    assert!(
+        autocorr.len() > order,
+        "Autocorrelation should have at least order + 1 elements"
+    );
+    assert!(
+        autocorr[0] > 0f64,
+        "Autocorrelation at lag 0 should be positive"
+    );
+
+    let mut a = vec![0f64; order];
+    let mut e = autocorr[0];
+    for m in 1..=order {
+        let acc: f64 = (1..m).map(|j| a[j - 1] * autocorr[m - j]).sum();
+        let k = (autocorr[m] - acc) / e;
+        let prev = a[..m - 1].to_vec();
+        for j in 1..m {
+            a[j - 1] = prev[j - 1] - k * prev[m - j - 1];
+        }
+        a[m - 1] = k;
+        e *= 1f64 - k * k;
+    }
+    (a, e)
+}
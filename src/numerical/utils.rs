@@ -1,7 +1,8 @@
 use crate::structure::matrix::*;
 use crate::structure::ad::*;
 use crate::structure::ad::AD::*;
-use crate::util::non_macro::{cat, zeros};
+use crate::traits::math::MatrixProduct;
+use crate::util::non_macro::{cat, eye, zeros};
 
 /// Jacobian Matrix
 ///
@@ -138,3 +139,166 @@ pub fn tdma(a_input: Vec<f64>, b_input: Vec<f64>, c_input: Vec<f64>, y_input: Ve
     }
     x.into()
 }
+
+/// Richardson extrapolation
+///
+/// # Description
+///
+/// `f(h)` is an approximation whose error expands in powers of $h^p$ (`p = order`):
+/// $f(h) = A + c_1 h^p + c_2 h^{2p} + \cdots$.
+/// Step sizes $h_0, h_0/2, \cdots, h_0/2^{\text{levels}-1}$ are used to build the
+/// extrapolation table, and its last diagonal entry is returned.
+///
+/// * Forward difference : `order = 1`
+/// * Central difference, trapezoidal rule (Romberg) : `order = 2`
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Central difference of sin at x = 1
+/// let d = |h: f64| ((1f64 + h).sin() - (1f64 - h).sin()) / (2f64 * h);
+/// let df = richardson(d, 0.1, 5, 2f64);
+/// assert!((df - 1f64.cos()).abs() < 1e-12);
+/// ```
+pub fn richardson<F: Fn(f64) -> f64>(f: F, h0: f64, levels: usize, order: f64) -> f64 {
+    assert!(levels > 0, "Richardson extrapolation requires at least one level");
+    let mut prev: Vec<f64> = vec![f(h0)];
+    let mut h = h0;
+    for i in 1..levels {
+        h /= 2f64;
+        let mut curr = vec![f(h)];
+        for k in 1..=i {
+            let factor = 2f64.powf(order * k as f64);
+            let r = curr[k - 1] + (curr[k - 1] - prev[k - 1]) / (factor - 1f64);
+            curr.push(r);
+        }
+        prev = curr;
+    }
+    prev[levels - 1]
+}
+
+/// 2D discrete Laplacian (5-point stencil)
+///
+/// # Description
+///
+/// Unknowns are the `nx * ny` interior grid points ordered with `x` fastest
+/// (index `i + nx * j` for $(x_i, y_j)$).
+/// The operator is the Kronecker sum $I_{n_y} \otimes D_x + D_y \otimes I_{n_x}$
+/// of 1D second-difference operators $D = \frac{1}{h^2}\text{tridiag}(1, -2, 1)$.
+///
+/// Dirichlet boundary: couplings to boundary points are dropped,
+/// so nonzero boundary values should be moved to the right-hand side.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let l = laplacian_2d(3, 2, 1f64, 1f64);
+/// assert_eq!((l.row, l.col), (6, 6));
+/// assert_eq!(l.row(4), vec![0f64, 1f64, 0f64, 1f64, -4f64, 1f64]);
+/// ```
+pub fn laplacian_2d(nx: usize, ny: usize, dx: f64, dy: f64) -> Matrix {
+    let d_x = second_difference(nx, dx);
+    let d_y = second_difference(ny, dy);
+    d_x.kron_identity(ny) + d_y.kronecker(&eye(nx))
+}
+
+/// 1D second-difference operator with Dirichlet boundary
+fn second_difference(n: usize, h: f64) -> Matrix {
+    let mut d = zeros(n, n);
+    let c = 1f64 / h.powi(2);
+    for i in 0..n {
+        d[(i, i)] = -2f64 * c;
+        if i > 0 {
+            d[(i, i - 1)] = c;
+        }
+        if i + 1 < n {
+            d[(i, i + 1)] = c;
+        }
+    }
+    d
+}
+
+/// Arc length of a planar polyline
+///
+/// # Description
+///
+/// Sum of segment lengths $\sum_i \sqrt{(x_{i+1} - x_i)^2 + (y_{i+1} - y_i)^2}$.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = vec![0f64, 3f64, 3f64];
+/// let y = vec![0f64, 4f64, 0f64];
+/// assert_eq!(arc_length(&x, &y), 9f64);
+/// ```
+pub fn arc_length(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), y.len(), "x and y should have the same length");
+    x.windows(2)
+        .zip(y.windows(2))
+        .map(|(xs, ys)| (xs[1] - xs[0]).hypot(ys[1] - ys[0]))
+        .sum()
+}
+
+/// Signed curvature of a sampled parametric curve
+///
+/// # Description
+///
+/// $\kappa = \frac{x' y'' - y' x''}{(x'^2 + y'^2)^{3/2}}$
+/// with derivatives with respect to the sample index
+/// (second order finite differences: central in the interior, one-sided at the ends).
+/// The curvature is independent of the parametrization,
+/// but the samples should be (nearly) equally spaced along the curve.
+///
+/// Counterclockwise turns are positive. Requires at least 3 points.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Parabola y = x^2 has curvature 2 at the origin
+/// let x = linspace(-1, 1, 201);
+/// let y = x.fmap(|t| t * t);
+/// let k = curvature(&x, &y);
+/// assert!((k[100] - 2f64).abs() < 1e-8);
+/// ```
+pub fn curvature(x: &[f64], y: &[f64]) -> Vec<f64> {
+    assert_eq!(x.len(), y.len(), "x and y should have the same length");
+    assert!(x.len() >= 3, "Curvature requires at least 3 points");
+    let (dx, ddx) = (index_derivative(x), index_second_derivative(x));
+    let (dy, ddy) = (index_derivative(y), index_second_derivative(y));
+    (0..x.len())
+        .map(|i| (dx[i] * ddy[i] - dy[i] * ddx[i]) / (dx[i].powi(2) + dy[i].powi(2)).powf(1.5))
+        .collect()
+}
+
+/// First derivative with respect to the index (second order)
+fn index_derivative(f: &[f64]) -> Vec<f64> {
+    let n = f.len();
+    let mut d = vec![0f64; n];
+    d[0] = (-3f64 * f[0] + 4f64 * f[1] - f[2]) / 2f64;
+    d[n - 1] = (3f64 * f[n - 1] - 4f64 * f[n - 2] + f[n - 3]) / 2f64;
+    for i in 1..n - 1 {
+        d[i] = (f[i + 1] - f[i - 1]) / 2f64;
+    }
+    d
+}
+
+/// Second derivative with respect to the index (second order for `n >= 4`)
+fn index_second_derivative(f: &[f64]) -> Vec<f64> {
+    let n = f.len();
+    let mut d = vec![0f64; n];
+    for i in 1..n - 1 {
+        d[i] = f[i + 1] - 2f64 * f[i] + f[i - 1];
+    }
+    if n >= 4 {
+        d[0] = 2f64 * f[0] - 5f64 * f[1] + 4f64 * f[2] - f[3];
+        d[n - 1] = 2f64 * f[n - 1] - 5f64 * f[n - 2] + 4f64 * f[n - 3] - f[n - 4];
+    } else {
+        d[0] = d[1];
+        d[n - 1] = d[n - 2];
+    }
+    d
+}
@@ -152,11 +152,11 @@ pub mod simpler;
 pub use crate::traits::{
     fp::{FPMatrix, FPVector},
     general::Algorithm,
-    math::{InnerProduct, LinearOp, MatrixProduct, Vector, VectorProduct},
+    math::{InnerProduct, LinearOp, MatrixProduct, StableExpLogOps, Vector, VectorProduct},
     mutable::{MutFP, MutMatrix},
-    num::Real,
+    num::{MatrixScalar, Real},
     pointer::{MatrixPtr, Oxide, Redox, RedoxCommon},
-    sugar::{Scalable, ScalableMut, VecOps, ConvToMat},
+    sugar::{Scalable, ScalableMut, VecOps, ConvToMat, CumOps},
 };
 
 pub use peroxide_num::{ExpLogOps, TrigOps, PowOps};
@@ -168,14 +168,16 @@ pub use crate::structure::{
     ad::*,
     ad::AD::*,
     matrix::{
-        combine, diag, gemm, gemv, gen_householder, inv_l, inv_u, matrix, ml_matrix, py_matrix,
-        r_matrix, Col, Matrix, Row, Shape, PQLU, QR, WAZD,
+        batched_matmul, batched_matmul_stacked, combine, diag, gemm, gemv, gen_householder, gram,
+        inv_l, inv_u, matrix, ml_matrix, py_matrix, r_matrix, Axis, Col, Matrix, Row, Shape, PQLU,
+        QR, WAZD,
     },
     polynomial::{Polynomial,poly,Calculus,lagrange_polynomial,legendre_polynomial},
     vector::*,
     dataframe::{
         DataFrame, DType, DTypeArray, DTypeValue, Series, Scalar, TypedScalar, TypedVector
     },
+    interval::Interval,
     //complex::C64,
 };
 #[cfg(feature="csv")]
@@ -184,6 +186,15 @@ pub use crate::structure::dataframe::WithCSV;
 #[cfg(feature="nc")]
 pub use crate::structure::dataframe::WithNetCDF;
 
+#[cfg(feature = "serde")]
+pub use crate::structure::dataframe::WithJSON;
+
+#[cfg(feature = "mmap")]
+pub use crate::structure::mmap::MmapMatrix;
+
+#[cfg(feature = "complex")]
+pub use crate::complex::{fft::*, C64};
+
 pub use simpler::{solve, SimplerLinearAlgebra};
 
 #[allow(unused_imports)]
@@ -202,6 +213,7 @@ pub use crate::numerical::{
     ode::*,
     optimize::*,
     root::*,
+    signal::*,
     spline::{cubic_spline, CubicSpline, CubicHermiteSpline, Spline},
     utils::*,
 };
@@ -8,6 +8,25 @@ pub fn gaussian(x: f64, mu: f64, sigma: f64) -> f64 {
     1f64 / ((2f64 * PI).sqrt() * sigma) * (-0.5 * ((x - mu) / sigma).powi(2)).exp()
 }
 
+/// Softplus function
+///
+/// `softplus(x) = ln(1 + exp(x))`
+///
+/// # Description
+/// Evaluated as `max(x, 0) + ln(1 + exp(-|x|))`, so it neither overflows for large `x`
+/// nor loses precision for very negative `x`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// assert_eq!(softplus(1000f64), 1000f64);
+/// assert!((softplus(0f64) - 2f64.ln()).abs() < 1e-15);
+/// ```
+pub fn softplus(x: f64) -> f64 {
+    x.max(0f64) + (-x.abs()).exp().ln_1p()
+}
+
 /// Gamma function
 ///
 /// # Description
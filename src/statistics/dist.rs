@@ -237,7 +237,7 @@ pub use self::TPDist::*;
 use crate::traits::fp::FPVector;
 use crate::special::function::*;
 //use statistics::rand::ziggurat;
-use crate::statistics::{ops::C, stat::Statistics};
+use crate::statistics::{ops::C, rand::global_rng, stat::Statistics};
 use crate::util::non_macro::{linspace, seq};
 use crate::util::useful::{auto_zip, find_interval};
 use std::f64::consts::E;
@@ -505,7 +505,7 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> ParametricDist for Weight
 pub trait RNG {
     /// Extract samples of distributions
     fn sample(&self, n: usize) -> Vec<f64> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.sample_with_rng(&mut rng, n)
     }
 
@@ -858,6 +858,39 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for OPDist<T>
     fn cor(&self) -> Self::Array {
         unimplemented!()
     }
+    /// Population variance (same as `var` for a distribution)
+    fn var_pop(&self) -> Self::Value {
+        self.var()
+    }
+
+    /// Population standard deviation (same as `sd` for a distribution)
+    fn std_pop(&self) -> Self::Value {
+        self.sd()
+    }
+
+    /// Geometric mean ($\exp E[\ln X]$)
+    ///
+    /// `0` if `0` has positive probability, `NaN` if the support has negative values.
+    fn geometric_mean(&self) -> Self::Value {
+        match self {
+            Bernoulli(mu) => {
+                if (*mu).into() < 1f64 { 0f64 } else { 1f64 }
+            }
+            StudentT(_) => f64::NAN,
+        }
+    }
+
+    /// Harmonic mean ($1 / E[1/X]$)
+    ///
+    /// `0` if `0` has positive probability, `NaN` if the support has negative values.
+    fn harmonic_mean(&self) -> Self::Value {
+        match self {
+            Bernoulli(mu) => {
+                if (*mu).into() < 1f64 { 0f64 } else { 1f64 }
+            }
+            StudentT(_) => f64::NAN,
+        }
+    }
 }
 
 impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for TPDist<T> {
@@ -905,6 +938,73 @@ impl<T: PartialOrd + SampleUniform + Copy + Into<f64>> Statistics for TPDist<T>
     fn cor(&self) -> Self::Array {
         unimplemented!()
     }
+    /// Population variance (same as `var` for a distribution)
+    fn var_pop(&self) -> Self::Value {
+        self.var()
+    }
+
+    /// Population standard deviation (same as `sd` for a distribution)
+    fn std_pop(&self) -> Self::Value {
+        self.sd()
+    }
+
+    /// Geometric mean ($\exp E[\ln X]$)
+    ///
+    /// `0` if $E[\ln X] = -\infty$, `NaN` if the support has negative values.
+    fn geometric_mean(&self) -> Self::Value {
+        match self {
+            Uniform(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if a < 0f64 {
+                    f64::NAN
+                } else {
+                    ((x_ln_x(b) - x_ln_x(a)) / (b - a) - 1f64).exp()
+                }
+            }
+            Binomial(n, mu) => {
+                if (*mu).into() < 1f64 { 0f64 } else { *n as f64 }
+            }
+            Normal(_m, _s) => f64::NAN,
+            Beta(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                (digamma(a) - digamma(a + b)).exp()
+            }
+            Gamma(a, b) => digamma((*a).into()).exp() / (*b).into(),
+        }
+    }
+
+    /// Harmonic mean ($1 / E[1/X]$)
+    ///
+    /// `0` if $E[1/X] = \infty$, `NaN` if the support has negative values.
+    fn harmonic_mean(&self) -> Self::Value {
+        match self {
+            Uniform(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if a < 0f64 {
+                    f64::NAN
+                } else {
+                    (b - a) / (b.ln() - a.ln())
+                }
+            }
+            Binomial(n, mu) => {
+                if (*mu).into() < 1f64 { 0f64 } else { *n as f64 }
+            }
+            Normal(_m, _s) => f64::NAN,
+            Beta(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if a > 1f64 { (a - 1f64) / (a + b - 1f64) } else { 0f64 }
+            }
+            Gamma(a, b) => {
+                let a: f64 = (*a).into();
+                let b: f64 = (*b).into();
+                if a > 1f64 { (a - 1f64) / b } else { 0f64 }
+            }
+        }
+    }
 }
 
 impl Statistics for WeightedUniform<f64> {
@@ -935,4 +1035,61 @@ impl Statistics for WeightedUniform<f64> {
     fn cor(&self) -> Self::Array {
         vec![1f64]
     }
+    /// Population variance (same as `var` for a distribution)
+    fn var_pop(&self) -> Self::Value {
+        self.var()
+    }
+
+    /// Population standard deviation (same as `sd` for a distribution)
+    fn std_pop(&self) -> Self::Value {
+        self.sd()
+    }
+
+    /// Geometric mean ($\exp E[\ln X]$)
+    ///
+    /// `NaN` if an interval has negative values.
+    fn geometric_mean(&self) -> Self::Value {
+        if self.intervals().iter().any(|(l, _)| *l < 0f64) {
+            return f64::NAN;
+        }
+        let e_ln = self.intervals().iter().zip(self.weights().iter())
+            .map(|((l, r), w)| w * (x_ln_x(*r) - r - x_ln_x(*l) + l))
+            .sum::<f64>() / self.sum;
+        e_ln.exp()
+    }
+
+    /// Harmonic mean ($1 / E[1/X]$)
+    ///
+    /// `0` if an interval starts at `0`, `NaN` if an interval has negative values.
+    fn harmonic_mean(&self) -> Self::Value {
+        if self.intervals().iter().any(|(l, _)| *l < 0f64) {
+            return f64::NAN;
+        }
+        let e_inv = self.intervals().iter().zip(self.weights().iter())
+            .map(|((l, r), w)| w * (r.ln() - l.ln()))
+            .sum::<f64>() / self.sum;
+        1f64 / e_inv
+    }
+}
+
+/// $x \ln x$ with the limit $0$ at $x = 0$
+fn x_ln_x(x: f64) -> f64 {
+    if x == 0f64 { 0f64 } else { x * x.ln() }
+}
+
+/// Digamma function $\psi(x)$ for $x > 0$
+///
+/// Recurrence up to $x \geq 10$, then the asymptotic series.
+fn digamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut result = 0f64;
+    while x < 10f64 {
+        result -= 1f64 / x;
+        x += 1f64;
+    }
+    let x2 = 1f64 / (x * x);
+    result + x.ln() - 0.5 / x
+        - x2 * (1f64 / 12f64
+            - x2 * (1f64 / 120f64
+                - x2 * (1f64 / 252f64 - x2 * (1f64 / 240f64 - x2 / 132f64))))
 }
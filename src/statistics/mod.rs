@@ -5,7 +5,10 @@
 //! * Simple Random Number Generator - `rand.rs`
 //! * Basic probabilistic operations - `ops.rs`
 
+#[cfg(feature = "std")]
 pub mod dist;
+#[cfg(feature = "std")]
 pub mod ops;
+#[cfg(feature = "std")]
 pub mod rand;
 pub mod stat;
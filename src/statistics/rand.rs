@@ -16,6 +16,25 @@
 //!
 //! * To want more detailed explanation, see [`rand` crate](https://crates.io/crates/rand)
 //!
+//! ## Reproducible convenience functions
+//!
+//! * Functions without rng argument (`rand`, `RNG::sample`, `rand!`, `runif!`, `prs`, ...)
+//!   draw from `global_rng()`, which is `thread_rng` by default.
+//! * `set_global_seed(seed)` makes them reproducible (per thread), and `clear_global_seed()`
+//!   restores `thread_rng`.
+//! * APIs taking an explicit rng (`sample_with_rng`, `rand_with_rng`, ...) bypass this.
+//!
+//!     ```rust
+//!     use peroxide::fuga::*;
+//!
+//!     set_global_seed(42);
+//!     let a = rand(2, 2);
+//!     set_global_seed(42);
+//!     let b = rand(2, 2);
+//!     assert_eq!(a, b);
+//!     clear_global_seed();
+//!     ```
+//!
 //! ## Piece-wise Rejection Sampling
 //!
 //!
@@ -23,11 +42,83 @@
 extern crate rand;
 use self::rand::distributions::uniform::SampleUniform;
 use self::rand::prelude::*;
+use std::cell::RefCell;
 
 #[allow(unused_imports)]
 use crate::structure::matrix::*;
 use crate::statistics::dist::{RNG, WeightedUniform};
 
+thread_local! {
+    static GLOBAL_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Seed the rng used by convenience random functions (for the current thread)
+///
+/// # Description
+/// After this call, functions without rng argument (`rand`, `RNG::sample`, `rand!`, `runif!`, ...)
+/// draw from a `StdRng` seeded with `seed` until `clear_global_seed` is called.
+/// APIs taking an explicit rng (`sample_with_rng`, `rand_with_rng`, ...) are not affected.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// set_global_seed(42);
+/// let a = Normal(0, 1).sample(3);
+/// set_global_seed(42);
+/// let b = Normal(0, 1).sample(3);
+/// assert_eq!(a, b);
+/// clear_global_seed();
+/// ```
+pub fn set_global_seed(seed: u64) {
+    GLOBAL_RNG.with(|r| *r.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Restore `thread_rng` for convenience random functions (for the current thread)
+pub fn clear_global_seed() {
+    GLOBAL_RNG.with(|r| *r.borrow_mut() = None);
+}
+
+/// Handle to the rng used by convenience random functions
+///
+/// # Description
+/// Draws from the seeded rng if `set_global_seed` was called on this thread,
+/// otherwise from `thread_rng`. Clones share the same underlying stream.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalRng;
+
+/// Rng used by convenience random functions (see `set_global_seed`)
+pub fn global_rng() -> GlobalRng {
+    GlobalRng
+}
+
+impl GlobalRng {
+    fn with<T>(&self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        GLOBAL_RNG.with(|r| match r.borrow_mut().as_mut() {
+            Some(rng) => f(rng),
+            None => f(&mut thread_rng()),
+        })
+    }
+}
+
+impl RngCore for GlobalRng {
+    fn next_u32(&mut self) -> u32 {
+        self.with(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.with(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.with(|rng| rng.try_fill_bytes(dest))
+    }
+}
+
 /// Small random number generator from seed
 ///
 /// # Examples
@@ -467,7 +558,7 @@ pub fn ziggurat(rng: &mut ThreadRng, sigma: f64) -> f64 {
 /// }
 pub fn prs<F>(f: F, n: usize, (a, b): (f64, f64), m: usize, eps: f64) -> anyhow::Result<Vec<f64>>
 where F: Fn(f64) -> f64 + Copy {
-    let mut rng = thread_rng();
+    let mut rng = global_rng();
 
     let mut result = vec![0f64; n];
 
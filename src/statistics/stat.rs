@@ -6,6 +6,9 @@
 //!     * `mean`: just mean
 //!     * `var` : variance
 //!     * `sd` : standard deviation (R-like notation)
+//!     * `std` : alias of `sd`
+//!     * `var_pop`, `std_pop` : population variance & standard deviation
+//!     * `geometric_mean`, `harmonic_mean` : means for positive data
 //!     * `cov` : covariance
 //!     * `cor` : correlation coefficient
 //!     ```rust
@@ -18,12 +21,20 @@
 //!         fn sd(&self) -> Self::Value;
 //!         fn cov(&self) -> Self::Array;
 //!         fn cor(&self) -> Self::Array;
+//!         fn var_pop(&self) -> Self::Value;
+//!         fn std_pop(&self) -> Self::Value;
+//!         fn geometric_mean(&self) -> Self::Value;
+//!         fn harmonic_mean(&self) -> Self::Value;
+//!
+//!         // Provided method
+//!         fn std(&self) -> Self::Value { self.sd() }
 //!     }
 //!     ```
 //!
 //! ### For `Vec<f64>`
 //!
 //! * Caution: For `Vec<f64>`, `cov` & `cor` are unimplemented (those for `Matrix`)
+//! * `var` & `sd` use the sample ($n-1$) denominator and return `NaN` for fewer than two data
 //!
 //!     ```rust
 //!     #[macro_use]
@@ -54,6 +65,10 @@
 //!     }
 //!     ```
 //!
+//! * For positive data, `geometric_mean` & `harmonic_mean` complement `mean`
+//!     * `mean >= geometric_mean >= harmonic_mean`
+//!     * The free functions of the same names accept slices
+//!
 //! ### For `Matrix`
 //!
 //! * For `Matrix`, `mean, var, sd` means column operations
@@ -108,6 +123,16 @@
 //! }
 //! ```
 //!
+//! ## Empirical distributions
+//!
+//! * `pdf_to_cdf(x, pdf)` : Cumulative trapezoidal integration (normalized)
+//! * `cdf_to_pdf(x, cdf)` : Finite differences
+//!
+//! ## Scalers
+//!
+//! * `StandardScaler` : Learn mean & standard deviation (`fit`) and standardize new data (`transform`, `inverse_transform`)
+//! * `MinMaxScaler` : Learn min & max and map data onto `[0, 1]` or a given range (optional clipping)
+//!
 //! ## Confusion Matrix
 //!
 //! * `ConfusionMatrix` is a struct to calculate confusion matrix
@@ -146,12 +171,17 @@
 //! }
 //! ```
 
-use std::fmt;
+use core::fmt;
 
+#[cfg(feature = "std")]
 use self::QType::*;
 //use crate::structure::dataframe::*;
 use crate::structure::matrix::*;
 use crate::traits::fp::FPVector;
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+#[cfg(feature = "std")]
 use order_stat::kth_by;
 
 /// Statistics Trait
@@ -166,6 +196,19 @@ pub trait Statistics {
     fn sd(&self) -> Self::Value;
     fn cov(&self) -> Self::Array;
     fn cor(&self) -> Self::Array;
+    /// Population variance
+    fn var_pop(&self) -> Self::Value;
+    /// Population standard deviation
+    fn std_pop(&self) -> Self::Value;
+    /// Geometric mean
+    fn geometric_mean(&self) -> Self::Value;
+    /// Harmonic mean
+    fn harmonic_mean(&self) -> Self::Value;
+
+    /// Standard deviation (alias of `sd`)
+    fn std(&self) -> Self::Value {
+        self.sd()
+    }
 }
 
 impl Statistics for Vec<f64> {
@@ -191,6 +234,9 @@ impl Statistics for Vec<f64> {
 
     /// Variance
     ///
+    /// # Description
+    /// Sample variance ($n-1$ denominator). `NaN` for fewer than two data.
+    ///
     /// # Examples
     /// ```
     /// #[macro_use]
@@ -200,20 +246,15 @@ impl Statistics for Vec<f64> {
     /// fn main() {
     ///     let a = c!(1,2,3,4,5);
     ///     assert_eq!(a.var(), 2.5);
+    ///     assert!(c!(1).var().is_nan());
     /// }
     /// ```
     fn var(&self) -> f64 {
-        let mut ss = 0f64;
-        let mut s = 0f64;
-        let mut l = 0f64;
-
-        for x in self.into_iter() {
-            ss += x.powf(2f64);
-            s += *x;
-            l += 1f64;
+        let l = self.len() as f64;
+        if l < 2f64 {
+            return f64::NAN;
         }
-        assert_ne!(l, 1f64);
-        (ss / l - (s / l).powf(2f64)) * l / (l - 1f64)
+        self.var_pop() * l / (l - 1f64)
     }
 
     /// Standard Deviation
@@ -239,6 +280,96 @@ impl Statistics for Vec<f64> {
     fn cor(&self) -> Vec<f64> {
         unimplemented!()
     }
+
+    /// Population variance
+    ///
+    /// # Description
+    /// $n$ denominator. `NaN` for empty data.
+    ///
+    /// Corrected two-pass algorithm: deviations from the mean are accumulated,
+    /// so there is no cancellation for data with a large mean.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = vec![1f64, 2f64, 3f64, 4f64];
+    /// assert_eq!(a.var_pop(), 1.25);
+    /// assert_eq!(a.std_pop(), 1.25f64.sqrt());
+    /// ```
+    fn var_pop(&self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+        let l = self.len() as f64;
+        let m = self.mean();
+        let (ss, s) = self.iter().fold((0f64, 0f64), |(ss, s), x| {
+            let d = x - m;
+            (ss + d * d, s + d)
+        });
+        // `s` is zero in exact arithmetic; it corrects the rounding error of `m`
+        ss / l - (s / l).powi(2)
+    }
+
+    fn std_pop(&self) -> f64 {
+        self.var_pop().sqrt()
+    }
+
+    /// Geometric mean
+    ///
+    /// # Description
+    /// $\exp\left(\frac{1}{n}\sum_i \ln x_i\right)$, suitable for growth rates & ratios.
+    /// `NaN` for empty data.
+    ///
+    /// # Panics
+    /// If any value is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 10, 100);
+    ///     assert!((a.geometric_mean() - 10f64).abs() < 1e-12);
+    /// }
+    /// ```
+    fn geometric_mean(&self) -> f64 {
+        assert!(
+            self.iter().all(|&t| t > 0f64),
+            "Geometric mean requires positive data"
+        );
+        (self.iter().map(|t| t.ln()).sum::<f64>() / self.len() as f64).exp()
+    }
+
+    /// Harmonic mean
+    ///
+    /// # Description
+    /// $\frac{n}{\sum_i 1 / x_i}$, suitable for averaging rates (e.g. speeds over equal distances).
+    /// `NaN` for empty data.
+    ///
+    /// # Panics
+    /// If any value is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1, 4, 4);
+    ///     assert_eq!(a.harmonic_mean(), 2f64);
+    /// }
+    /// ```
+    fn harmonic_mean(&self) -> f64 {
+        assert!(
+            self.iter().all(|&t| t > 0f64),
+            "Harmonic mean requires positive data"
+        );
+        self.len() as f64 / self.iter().map(|t| 1f64 / t).sum::<f64>()
+    }
 }
 
 impl Statistics for Matrix {
@@ -360,6 +491,56 @@ impl Statistics for Matrix {
         }
         m
     }
+
+    /// Column population variance
+    fn var_pop(&self) -> Vec<f64> {
+        (0..self.col).map(|i| self.col(i).var_pop()).collect()
+    }
+
+    /// Column population standard deviation
+    fn std_pop(&self) -> Vec<f64> {
+        (0..self.col).map(|i| self.col(i).std_pop()).collect()
+    }
+
+    /// Column geometric mean
+    ///
+    /// # Panics
+    /// If any value is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let m = matrix(c!(1,4,2,8), 2, 2, Col);
+    ///     assert_eq!(m.geometric_mean(), c!(2,4));
+    /// }
+    /// ```
+    fn geometric_mean(&self) -> Vec<f64> {
+        (0..self.col).map(|i| self.col(i).geometric_mean()).collect()
+    }
+
+    /// Column harmonic mean
+    ///
+    /// # Panics
+    /// If any value is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let m = matrix(c!(1,4,4,2,2,2), 3, 2, Col);
+    ///     assert_eq!(m.harmonic_mean(), c!(2,2));
+    /// }
+    /// ```
+    fn harmonic_mean(&self) -> Vec<f64> {
+        (0..self.col).map(|i| self.col(i).harmonic_mean()).collect()
+    }
 }
 
 //impl Statistics for DataFrame {
@@ -402,6 +583,12 @@ impl Statistics for Matrix {
 
 /// Covariance (to Value)
 ///
+/// # Description
+/// Sample covariance ($n-1$ denominator). `NaN` for fewer than two data.
+///
+/// # Panics
+/// If the lengths of `v1` and `v2` differ.
+///
 /// # Examples
 /// ```
 /// #[macro_use]
@@ -415,19 +602,27 @@ impl Statistics for Matrix {
 /// }
 /// ```
 pub fn cov(v1: &Vec<f64>, v2: &Vec<f64>) -> f64 {
-    let mut ss = 0f64;
-    let mut sx = 0f64;
-    let mut sy = 0f64;
-    let mut l = 0f64;
-
-    for (x, y) in v1.into_iter().zip(v2) {
-        ss += x * y;
-        sx += *x;
-        sy += *y;
-        l += 1f64;
-    }
-    assert_ne!(l, 1f64);
-    (ss / l - (sx * sy) / (l.powf(2f64))) * l / (l - 1f64)
+    assert_eq!(
+        v1.len(),
+        v2.len(),
+        "cov: length mismatch ({} vs {})",
+        v1.len(),
+        v2.len()
+    );
+    let l = v1.len() as f64;
+    if l < 2f64 {
+        return f64::NAN;
+    }
+    // Two-pass: products of deviations from the means
+    let (mx, my) = (v1.mean(), v2.mean());
+    let (mut sxy, mut sx, mut sy) = (0f64, 0f64, 0f64);
+    for (x, y) in v1.iter().zip(v2) {
+        let (dx, dy) = (x - mx, y - my);
+        sxy += dx * dy;
+        sx += dx;
+        sy += dy;
+    }
+    (sxy - sx * sy / l) / (l - 1f64)
 }
 
 /// Pearson's correlation coefficient
@@ -448,6 +643,119 @@ pub fn cor(v1: &Vec<f64>, v2: &Vec<f64>) -> f64 {
     cov(v1, v2) / (v1.sd() * v2.sd())
 }
 
+/// Geometric mean of a slice
+///
+/// # Description
+/// Slice version of `Statistics::geometric_mean`.
+///
+/// # Panics
+/// If any value is not positive.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let g = geometric_mean(&[1f64, 10f64, 100f64]);
+/// assert!((g - 10f64).abs() < 1e-12);
+/// ```
+pub fn geometric_mean(x: &[f64]) -> f64 {
+    x.to_vec().geometric_mean()
+}
+
+/// Harmonic mean of a slice
+///
+/// # Description
+/// Slice version of `Statistics::harmonic_mean`.
+///
+/// # Panics
+/// If any value is not positive.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// assert_eq!(harmonic_mean(&[1f64, 4f64, 4f64]), 2f64);
+/// ```
+pub fn harmonic_mean(x: &[f64]) -> f64 {
+    x.to_vec().harmonic_mean()
+}
+
+/// Summary statistics of data
+///
+/// * `count` : number of data
+/// * `min`, `max` : extremes
+/// * `mean` : mean
+/// * `var` : sample variance
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub var: f64,
+}
+
+/// Single-pass summary statistics (Welford's algorithm for variance)
+///
+/// # Description
+/// For empty data, `min, max, mean, var` are `NaN`.
+/// For single data, `var` is `NaN`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate peroxide;
+/// use peroxide::fuga::*;
+///
+/// fn main() {
+///     let a = c!(1,2,3,4,5);
+///     let s = summary(&a);
+///     assert_eq!(s.count, 5);
+///     assert_eq!((s.min, s.max), (1f64, 5f64));
+///     assert_eq!(s.mean, 3f64);
+///     assert_eq!(s.var, 2.5);
+/// }
+/// ```
+pub fn summary(x: &[f64]) -> Summary {
+    if x.is_empty() {
+        return Summary {
+            count: 0,
+            min: f64::NAN,
+            max: f64::NAN,
+            mean: f64::NAN,
+            var: f64::NAN,
+        };
+    }
+
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut mean = 0f64;
+    let mut m2 = 0f64;
+
+    for (i, &t) in x.iter().enumerate() {
+        min = min.min(t);
+        max = max.max(t);
+        let delta = t - mean;
+        mean += delta / (i + 1) as f64;
+        m2 += delta * (t - mean);
+    }
+
+    let count = x.len();
+    let var = if count > 1 {
+        m2 / (count - 1) as f64
+    } else {
+        f64::NAN
+    };
+
+    Summary {
+        count,
+        min,
+        max,
+        mean,
+        var,
+    }
+}
+
 /// R like linear regression
 ///
 /// # Examples
@@ -503,6 +811,7 @@ pub enum QType {
     Type9,
 }
 
+#[cfg(feature = "std")]
 impl OrderedStat for Vec<f64> {
     type Array = Self;
     type Value = f64;
@@ -526,6 +835,7 @@ impl OrderedStat for Vec<f64> {
     }
 }
 
+#[cfg(feature = "std")]
 fn quantile_mut(v: &mut [f64], q: f64, t: QType) -> f64 {
     let l = v.len();
     let p = 1f64 / (l as f64);
@@ -562,11 +872,333 @@ fn quantile_mut(v: &mut [f64], q: f64, t: QType) -> f64 {
     }
 }
 
+#[cfg(feature = "std")]
 pub fn quantile(v: &Vec<f64>, qtype: QType) -> Vec<f64> {
     let q_vec = vec![0.0, 0.25, 0.5, 0.75, 1.0];
     v.quantiles(q_vec, qtype)
 }
 
+/// Most frequent value(s)
+///
+/// # Description
+/// Sorted values are grouped when they lie within `tol` of the smallest value of the group,
+/// and each group is represented by its mean.
+/// All groups sharing the largest count are returned in ascending order (multi-modal data).
+/// `NaN`s are ignored, and empty data gives an empty vector.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = vec![1f64, 2f64, 2f64, 3f64];
+/// assert_eq!(mode(&a, 0f64), vec![2f64]);
+///
+/// let b = vec![1f64, 1f64, 2f64, 3f64, 3f64];
+/// assert_eq!(mode(&b, 0f64), vec![1f64, 3f64]);
+///
+/// let c = vec![0.99, 1.01, 1.0, 5.0];
+/// assert_eq!(mode(&c, 0.05), vec![1f64]);
+/// ```
+pub fn mode(x: &[f64], tol: f64) -> Vec<f64> {
+    assert!(tol >= 0f64, "Tolerance should be non-negative");
+    let mut v = x.iter().copied().filter(|t| !t.is_nan()).collect::<Vec<f64>>();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // (representative, count)
+    let mut groups: Vec<(f64, usize)> = vec![];
+    let mut i = 0usize;
+    while i < v.len() {
+        let start = v[i];
+        let mut j = i;
+        let mut s = 0f64;
+        while j < v.len() && v[j] - start <= tol {
+            s += v[j];
+            j += 1;
+        }
+        let n = j - i;
+        let rep = if n == 1 || tol == 0f64 { start } else { s / n as f64 };
+        groups.push((rep, n));
+        i = j;
+    }
+
+    let max_count = groups.iter().map(|&(_, n)| n).max().unwrap_or(0);
+    groups
+        .into_iter()
+        .filter(|&(_, n)| n == max_count)
+        .map(|(rep, _)| rep)
+        .collect()
+}
+
+/// Remove outliers outside `[Q1 - k*IQR, Q3 + k*IQR]` (Tukey's fences)
+///
+/// # Description
+/// Quartiles are computed with `QType::Type2`. Order of remaining values is preserved.
+/// `k = 1.5` is the conventional choice.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = vec![1f64, 2f64, 3f64, 4f64, 5f64, 100f64];
+/// assert_eq!(remove_outliers_iqr(&a, 1.5), vec![1f64, 2f64, 3f64, 4f64, 5f64]);
+/// ```
+#[cfg(feature = "std")]
+pub fn remove_outliers_iqr(x: &[f64], k: f64) -> Vec<f64> {
+    if x.is_empty() {
+        return vec![];
+    }
+    let q = x.to_vec().quantiles(vec![0.25, 0.75], Type2);
+    let iqr = q[1] - q[0];
+    let (lower, upper) = (q[0] - k * iqr, q[1] + k * iqr);
+    x.iter().copied().filter(|&t| lower <= t && t <= upper).collect()
+}
+
+/// Remove values whose z-score `|x - mean| / sd` exceeds `threshold`
+///
+/// # Description
+/// `sd` is the sample standard deviation. If it is zero (or undefined), every value is retained.
+/// Order of remaining values is preserved.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = vec![10f64, 11f64, 9f64, 10f64, 12f64, 10f64, 11f64, 9f64, 10f64, 100f64];
+/// let b = remove_outliers_zscore(&a, 2.5);
+/// assert_eq!(b, a[..9].to_vec());
+/// ```
+pub fn remove_outliers_zscore(x: &[f64], threshold: f64) -> Vec<f64> {
+    let v = x.to_vec();
+    let (m, s) = (v.mean(), v.sd());
+    if s.is_nan() || s == 0f64 {
+        return v;
+    }
+    v.into_iter().filter(|&t| (t - m).abs() / s <= threshold).collect()
+}
+
+/// Cumulative distribution from a sampled density
+///
+/// # Description
+/// Cumulative trapezoidal integration of `pdf` over the increasing grid `x`,
+/// normalized so that the last value is `1` (the first value is `0`).
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Triangular density on [0, 2]
+/// let x = vec![0f64, 1f64, 2f64];
+/// let pdf = vec![0f64, 1f64, 0f64];
+/// assert_eq!(pdf_to_cdf(&x, &pdf), vec![0f64, 0.5, 1f64]);
+/// ```
+pub fn pdf_to_cdf(x: &[f64], pdf: &[f64]) -> Vec<f64> {
+    assert_eq!(x.len(), pdf.len(), "x and pdf should have the same length");
+    assert!(x.len() >= 2, "At least two points are required");
+    let mut cdf = Vec::with_capacity(x.len());
+    cdf.push(0f64);
+    for i in 1..x.len() {
+        let area = (x[i] - x[i - 1]) * (pdf[i] + pdf[i - 1]) / 2f64;
+        cdf.push(cdf[i - 1] + area);
+    }
+    let total = cdf[x.len() - 1];
+    if total > 0f64 {
+        cdf.iter_mut().for_each(|c| *c /= total);
+    }
+    cdf
+}
+
+/// Density from a sampled cumulative distribution
+///
+/// # Description
+/// Second order finite differences on the (possibly non-uniform) grid `x`:
+/// three-point central differences in the interior and one-sided at both ends.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// // Uniform distribution on [0, 2]
+/// let x = vec![0f64, 0.5, 1f64, 2f64];
+/// let cdf = x.fmap(|t| t / 2f64);
+/// assert!(eq_vec(&cdf_to_pdf(&x, &cdf), &vec![0.5; 4], 1e-12));
+/// ```
+pub fn cdf_to_pdf(x: &[f64], cdf: &[f64]) -> Vec<f64> {
+    assert_eq!(x.len(), cdf.len(), "x and cdf should have the same length");
+    let n = x.len();
+    assert!(n >= 3, "At least three points are required");
+    let h = x.windows(2).map(|w| w[1] - w[0]).collect::<Vec<f64>>();
+    let mut pdf = vec![0f64; n];
+
+    let (h0, h1) = (h[0], h[1]);
+    pdf[0] = -(2f64 * h0 + h1) / (h0 * (h0 + h1)) * cdf[0] + (h0 + h1) / (h0 * h1) * cdf[1]
+        - h0 / (h1 * (h0 + h1)) * cdf[2];
+    for i in 1..n - 1 {
+        let (a, b) = (h[i - 1], h[i]);
+        pdf[i] = (a * a * cdf[i + 1] - b * b * cdf[i - 1] + (b * b - a * a) * cdf[i])
+            / (a * b * (a + b));
+    }
+    let (h0, h1) = (h[n - 2], h[n - 3]);
+    pdf[n - 1] = (2f64 * h0 + h1) / (h0 * (h0 + h1)) * cdf[n - 1]
+        - (h0 + h1) / (h0 * h1) * cdf[n - 2]
+        + h0 / (h1 * (h0 + h1)) * cdf[n - 3];
+    pdf
+}
+
+// =============================================================================
+// Scalers
+// =============================================================================
+/// Standardization with stored parameters (z-score)
+///
+/// # Description
+/// `fit` learns `mean` and `std` (sample standard deviation) from training data,
+/// then `transform` maps $x \mapsto (x - \mu) / \sigma$ for any data.
+/// If `std` is zero or undefined (fewer than two data), `1` is used instead,
+/// so constant features are only centered.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let train = vec![1f64, 2f64, 3f64, 4f64, 5f64];
+/// let scaler = StandardScaler::fit(&train);
+/// assert_eq!(scaler.mean, 3f64);
+///
+/// assert_eq!(scaler.std, 2.5f64.sqrt());
+///
+/// // Apply to new data
+/// let x = vec![3f64, 8f64];
+/// let z = scaler.transform(&x);
+/// assert_eq!(z[0], 0f64);
+/// assert!(eq_vec(&scaler.inverse_transform(&z), &x, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardScaler {
+    pub mean: f64,
+    pub std: f64,
+}
+
+impl StandardScaler {
+    /// Learn mean & standard deviation
+    pub fn fit(data: &[f64]) -> Self {
+        let s = summary(data);
+        let std = s.var.sqrt();
+        StandardScaler {
+            mean: s.mean,
+            std: if std.is_nan() || std == 0f64 { 1f64 } else { std },
+        }
+    }
+
+    /// Standardize `x` with the fitted parameters
+    pub fn transform(&self, x: &[f64]) -> Vec<f64> {
+        x.iter().map(|t| (t - self.mean) / self.std).collect()
+    }
+
+    /// Undo `transform`
+    pub fn inverse_transform(&self, z: &[f64]) -> Vec<f64> {
+        z.iter().map(|t| t * self.std + self.mean).collect()
+    }
+
+    /// `fit` & `transform` the same data
+    pub fn fit_transform(data: &[f64]) -> (Self, Vec<f64>) {
+        let scaler = Self::fit(data);
+        let z = scaler.transform(data);
+        (scaler, z)
+    }
+}
+
+/// Min-max scaling with stored parameters
+///
+/// # Description
+/// `fit` learns `min` and `max` from training data,
+/// then `transform` maps `[min, max]` linearly onto `range` (default `(0, 1)`).
+/// If `max == min`, the span is taken as `1`, so the data maps to the lower end of `range`.
+///
+/// Values outside the fitted range are extrapolated, or clipped to `range` if `clip` is set.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let mut scaler = MinMaxScaler::fit(&[2f64, 4f64, 6f64]);
+/// assert_eq!(scaler.transform(&[2f64, 5f64, 8f64]), vec![0f64, 0.75, 1.5]);
+///
+/// scaler.set_range(-1f64, 1f64).set_clip(true);
+/// assert_eq!(scaler.transform(&[2f64, 5f64, 8f64]), vec![-1f64, 0.5, 1f64]);
+/// assert_eq!(scaler.inverse_transform(&[-1f64, 0.5]), vec![2f64, 5f64]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MinMaxScaler {
+    pub min: f64,
+    pub max: f64,
+    pub range: (f64, f64),
+    pub clip: bool,
+}
+
+impl MinMaxScaler {
+    /// Learn min & max (target range `(0, 1)`, no clipping)
+    pub fn fit(data: &[f64]) -> Self {
+        let s = summary(data);
+        MinMaxScaler {
+            min: s.min,
+            max: s.max,
+            range: (0f64, 1f64),
+            clip: false,
+        }
+    }
+
+    /// Set target range
+    pub fn set_range(&mut self, lower: f64, upper: f64) -> &mut Self {
+        assert!(lower < upper, "Lower bound should be smaller than upper bound");
+        self.range = (lower, upper);
+        self
+    }
+
+    /// Clip transformed values to the target range
+    pub fn set_clip(&mut self, clip: bool) -> &mut Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Scale `x` with the fitted parameters
+    pub fn transform(&self, x: &[f64]) -> Vec<f64> {
+        let (lower, upper) = self.range;
+        let scale = (upper - lower) / self.span();
+        x.iter()
+            .map(|t| {
+                let y = lower + (t - self.min) * scale;
+                if self.clip {
+                    y.clamp(lower, upper)
+                } else {
+                    y
+                }
+            })
+            .collect()
+    }
+
+    /// Undo `transform` (clipped values are not recovered)
+    pub fn inverse_transform(&self, y: &[f64]) -> Vec<f64> {
+        let (lower, upper) = self.range;
+        let scale = self.span() / (upper - lower);
+        y.iter().map(|t| self.min + (t - lower) * scale).collect()
+    }
+
+    /// `fit` & `transform` the same data
+    pub fn fit_transform(data: &[f64]) -> (Self, Vec<f64>) {
+        let scaler = Self::fit(data);
+        let y = scaler.transform(data);
+        (scaler, y)
+    }
+
+    fn span(&self) -> f64 {
+        let span = self.max - self.min;
+        if span == 0f64 {
+            1f64
+        } else {
+            span
+        }
+    }
+}
+
 // =============================================================================
 // Confusion Matrix
 // =============================================================================
@@ -849,6 +1481,7 @@ impl ConfusionMatrix {
     }
 
     /// Summarize some metrics
+    #[cfg(feature = "std")]
     pub fn summary(&self, metrics: &[Metric]) {
         let width = metrics.iter().fold(0, |acc, m| {
             if m.to_string().len() > acc {
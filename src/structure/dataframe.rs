@@ -49,6 +49,8 @@
 //!         pub fn len(&self) -> usize;
 //!         pub fn to_type(&self, dtype: DType) -> Series;
 //!         pub fn as_type(&mut self, dtype: DType);
+//!         pub fn unique(&self) -> Series;
+//!         pub fn value_counts(&self) -> DataFrame;
 //!     }
 //!     ```
 //!
@@ -58,6 +60,8 @@
 //!         * All integer & float types can be exchanged.
 //!         * `Bool, Char` can be changed to `Str` or `U8` only.
 //!         * `U8` can be changed to all types.
+//!     * `unique` returns distinct values in first-seen order.
+//!     * `value_counts` returns a `DataFrame` with columns `value` and `count` (descending count).
 //! 
 //! ### 3. Example
 //!
@@ -130,12 +134,19 @@
 //!         pub fn row(&self, i: usize) -> DataFrame;
 //!         pub fn spread(&self) -> String;
 //!         pub fn as_types(&mut self, dtypes: Vec<DType>);
+//!         pub fn schema(&self) -> Vec<(String, DType)>;
+//!         pub fn iter_columns(&self) -> impl Iterator<Item = (&str, &Series)>;
+//!         pub fn sort_by(&self, column: &str, ascending: bool) -> DataFrame;
+//!         pub fn inner_join(&self, other: &DataFrame, left_key: &str, right_key: &str) -> DataFrame;
 //!     }
 //!     ```
 //!
 //!     * `push(&mut self, name: &str, series: Series)`: push head & Series pair
 //!     * `drop(&mut self, col_header: &str)`: drop specific column by header
 //!     * `row(&self, i: usize) -> DataFrame` : Extract $i$-th row as new DataFrame
+//!     * `schema(&self) -> Vec<(String, DType)>` : Pairs of header & dtype
+//!     * `iter_columns(&self)` : Iterator over pairs of header & Series
+//!     * `sort_by(&self, column: &str, ascending: bool) -> DataFrame` : Sort rows by specific column
 //!
 //! * `WithCSV` trait
 //!
@@ -254,14 +265,47 @@
 //!         Ok(())
 //!     }
 //!     ```
+//!
+//! * `WithJSON` trait
+//!
+//!     ```ignore
+//!     pub trait WithJSON: Sized {
+//!         fn to_json(&self) -> String;
+//!         fn from_json(s: &str) -> Result<Self, Box<dyn Error>>;
+//!     }
+//!     ```
+//!
+//!     * `serde` feature should be required
+//!     * Implemented for `DataFrame` (header, dtypes & values) and `Matrix` (row, col, shape & data)
+//!
+//!     ```
+//!     use peroxide::fuga::*;
+//!
+//!     fn main() -> Result<(), Box<dyn Error>> {
+//!     #    #[cfg(feature = "serde")]
+//!     #    {
+//!         let mut df = DataFrame::new(vec![]);
+//!         df.push("a", Series::new(vec!['x', 'y', 'z']));
+//!         df.push("b", Series::new(vec![0.1, 0.2, 0.3]));
+//!
+//!         let s = df.to_json();
+//!         let dg = DataFrame::from_json(&s)?;
+//!         assert_eq!(df, dg);
+//!     #    }
+//!
+//!         Ok(())
+//!     }
+//!     ```
 
 #[cfg(feature="csv")]
 use std::collections::HashMap;
 use std::fmt;
 use std::ops::{Index, IndexMut};
-use std::cmp::{max, min};
-#[cfg(any(feature="csv", feature="nc", feature="parquet"))]
+use std::cmp::{max, min, Ordering};
+#[cfg(any(feature="csv", feature="nc", feature="parquet", feature="serde"))]
 use std::error::Error;
+#[cfg(feature="serde")]
+use serde::{Deserialize, Serialize};
 use crate::util::{
     useful::tab,
     print::LowerExpWithPlus,
@@ -313,6 +357,7 @@ use arrow2::{
 
 /// Data Type enum
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DType {
     USIZE,
     U8,
@@ -333,6 +378,7 @@ pub enum DType {
 
 /// Vector with `DType`
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DTypeArray {
     USIZE(Vec<usize>),
     U8(Vec<u8>),
@@ -344,7 +390,9 @@ pub enum DTypeArray {
     I16(Vec<i16>),
     I32(Vec<i32>),
     I64(Vec<i64>),
+    #[cfg_attr(feature = "serde", serde(with = "non_finite_serde"))]
     F32(Vec<f32>),
+    #[cfg_attr(feature = "serde", serde(with = "non_finite_serde"))]
     F64(Vec<f64>),
     Bool(Vec<bool>),
     Str(Vec<String>),
@@ -406,6 +454,7 @@ pub enum DTypeValue {
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataFrame {
     pub data: Vec<Series>,
     pub ics: Vec<String>,
@@ -432,6 +481,7 @@ pub struct DataFrame {
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Series {
     pub values: DTypeArray,
     pub dtype: DType,
@@ -1033,6 +1083,62 @@ where Series: TypedVector<T> {
     Series::new(v.into_iter().map(|x| x * s).collect::<Vec<T>>())
 }
 
+fn select_vec<T: Clone>(v: Vec<T>, indices: &[usize]) -> Series
+where Series: TypedVector<T> {
+    Series::new(indices.iter().map(|&i| v[i].clone()).collect::<Vec<T>>())
+}
+
+/// Indices which sort `v` (stable, NaN-like values are placed at the end)
+fn sort_indices<T: PartialOrd>(v: Vec<T>, ascending: bool) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0 .. v.len()).collect();
+    indices.sort_by(|&i, &j| {
+        let (x, y) = (&v[i], &v[j]);
+        match (x.partial_cmp(x).is_none(), y.partial_cmp(y).is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let ord = x.partial_cmp(y).unwrap();
+                if ascending { ord } else { ord.reverse() }
+            }
+        }
+    });
+    indices
+}
+
+/// Index pairs `(i, j)` with `l[i] == r[j]` (ordered by `i`, then `j`)
+fn join_indices<T: PartialEq>(l: Vec<T>, r: Vec<T>) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    for (i, x) in l.iter().enumerate() {
+        for (j, y) in r.iter().enumerate() {
+            if x == y {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// First-seen index and count of each distinct value (NaN-like values are grouped together)
+fn distinct_counts<T: PartialOrd>(v: Vec<T>) -> (Vec<usize>, Vec<usize>) {
+    let mut first: Vec<usize> = vec![];
+    let mut counts: Vec<usize> = vec![];
+    for (i, x) in v.iter().enumerate() {
+        let pos = first.iter().position(|&k| {
+            let y = &v[k];
+            x == y || (x.partial_cmp(x).is_none() && y.partial_cmp(y).is_none())
+        });
+        match pos {
+            Some(p) => counts[p] += 1,
+            None => {
+                first.push(i);
+                counts.push(1);
+            }
+        }
+    }
+    (first, counts)
+}
+
 // =============================================================================
 // Implementations of DType variables
 // =============================================================================
@@ -1084,6 +1190,29 @@ impl fmt::Display for DType {
     }
 }
 
+impl DTypeArray {
+    /// `DType` of array
+    pub fn dtype(&self) -> DType {
+        match self {
+            DTypeArray::USIZE(_) => USIZE,
+            DTypeArray::U8(_) => U8,
+            DTypeArray::U16(_) => U16,
+            DTypeArray::U32(_) => U32,
+            DTypeArray::U64(_) => U64,
+            DTypeArray::ISIZE(_) => ISIZE,
+            DTypeArray::I8(_) => I8,
+            DTypeArray::I16(_) => I16,
+            DTypeArray::I32(_) => I32,
+            DTypeArray::I64(_) => I64,
+            DTypeArray::F32(_) => F32,
+            DTypeArray::F64(_) => F64,
+            DTypeArray::Bool(_) => Bool,
+            DTypeArray::Str(_) => Str,
+            DTypeArray::Char(_) => Char,
+        }
+    }
+}
+
 impl fmt::Display for DTypeArray {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let st = match self {
@@ -1172,6 +1301,66 @@ impl Series {
         self.dtype = x.dtype;
         self.values = x.values;
     }
+
+    /// Distinct values in first-seen order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = Series::new(vec![3, 1, 3, 2, 1]);
+    ///     assert_eq!(a.unique(), Series::new(vec![3, 1, 2]));
+    /// }
+    /// ```
+    pub fn unique(&self) -> Series {
+        let (first, _) = dtype_match!(self.dtype, self.to_vec(), distinct_counts; Vec);
+        self.select_indices(&first)
+    }
+
+    /// Frequency of each distinct value
+    ///
+    /// # Description
+    /// Returns a `DataFrame` with columns `value` (same dtype as `self`) and `count` (`usize`).
+    /// Rows are sorted by descending count, and ties are broken by ascending value.
+    /// `NaN`s are counted together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = Series::new(vec!['b', 'a', 'b', 'c']);
+    ///     let vc = a.value_counts();
+    ///     assert_eq!(vc["value"], Series::new(vec!['b', 'a', 'c']));
+    ///     assert_eq!(vc["count"], Series::new(vec![2usize, 1, 1]));
+    /// }
+    /// ```
+    pub fn value_counts(&self) -> DataFrame {
+        let (first, counts) = dtype_match!(self.dtype, self.to_vec(), distinct_counts; Vec);
+        let values = self.select_indices(&first);
+        let mut order: Vec<usize> = (0 .. first.len()).collect();
+        let by_value = dtype_match!(values.dtype, values.to_vec(), |x| sort_indices(x, true); Vec);
+        let mut rank = vec![0usize; first.len()];
+        for (r, &i) in by_value.iter().enumerate() {
+            rank[i] = r;
+        }
+        order.sort_by(|&i, &j| counts[j].cmp(&counts[i]).then(rank[i].cmp(&rank[j])));
+
+        let mut df = DataFrame::new(vec![]);
+        df.push("value", values.select_indices(&order));
+        df.push("count", Series::new(order.iter().map(|&i| counts[i]).collect::<Vec<usize>>()));
+        df
+    }
+
+    /// Select elements by indices
+    fn select_indices(&self, indices: &[usize]) -> Series {
+        dtype_match!(self.dtype, self.to_vec(), |x| select_vec(x, indices); Vec)
+    }
 }
 
 impl Vector for Series {
@@ -1594,6 +1783,128 @@ impl DataFrame {
             None => panic!("Can't drop header '{}'", col_header),
         }
     }
+
+    /// Schema of DataFrame (pairs of header & dtype)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.push("a", Series::new(vec![1, 2]));
+    ///     df.push("b", Series::new(vec!['x', 'y']));
+    ///
+    ///     assert_eq!(df.schema(), vec![("a".to_string(), I32), ("b".to_string(), Char)]);
+    /// }
+    /// ```
+    pub fn schema(&self) -> Vec<(String, DType)> {
+        self.iter_columns()
+            .map(|(h, s)| (h.to_string(), s.dtype))
+            .collect()
+    }
+
+    /// Iterator over pairs of header & Series
+    pub fn iter_columns(&self) -> impl Iterator<Item = (&str, &Series)> {
+        self.ics.iter().map(|h| h.as_str()).zip(self.data.iter())
+    }
+
+    /// Sort rows by specific column
+    ///
+    /// # Description
+    /// All columns are reordered according to the sorted order of `column`.
+    /// NaN values are placed at the end regardless of `ascending`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut df = DataFrame::new(vec![]);
+    ///     df.push("a", Series::new(vec![2, 3, 1]));
+    ///     df.push("b", Series::new(vec!['y', 'z', 'x']));
+    ///
+    ///     let dg = df.sort_by("a", true);
+    ///     assert_eq!(dg["a"], Series::new(vec![1, 2, 3]));
+    ///     assert_eq!(dg["b"], Series::new(vec!['x', 'y', 'z']));
+    /// }
+    /// ```
+    pub fn sort_by(&self, column: &str, ascending: bool) -> DataFrame {
+        let key = &self[column];
+        let n = key.len();
+        assert!(
+            self.data.iter().all(|s| s.len() == n),
+            "All columns should have same length to sort"
+        );
+        let indices = dtype_match!(key.dtype, key.to_vec(), |x| sort_indices(x, ascending); Vec);
+
+        let mut df = DataFrame::new(vec![]);
+        for (h, s) in self.iter_columns() {
+            df.push(h, s.select_indices(&indices));
+        }
+        df
+    }
+
+    /// Inner join with other DataFrame on key columns
+    ///
+    /// # Description
+    /// * Rows are matched where `self[left_key] == other[right_key]`.
+    /// * Repeated key values produce every combination of matching rows (as in SQL).
+    /// * Result has all columns of `self`, then those of `other` except `right_key`.
+    /// * Columns of `other` whose header already exists get the suffix `_right`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let mut a = DataFrame::new(vec![]);
+    ///     a.push("id", Series::new(vec!['a', 'b', 'c']));
+    ///     a.push("x", Series::new(vec![1, 2, 3]));
+    ///
+    ///     let mut b = DataFrame::new(vec![]);
+    ///     b.push("key", Series::new(vec!['c', 'a', 'd']));
+    ///     b.push("y", Series::new(vec![0.3, 0.1, 0.4]));
+    ///
+    ///     let c = a.inner_join(&b, "id", "key");
+    ///     assert_eq!(c.header(), &vec!["id".to_string(), "x".to_string(), "y".to_string()]);
+    ///     assert_eq!(c["id"], Series::new(vec!['a', 'c']));
+    ///     assert_eq!(c["y"], Series::new(vec![0.1, 0.3]));
+    /// }
+    /// ```
+    pub fn inner_join(&self, other: &DataFrame, left_key: &str, right_key: &str) -> DataFrame {
+        let lk = &self[left_key];
+        let rk = &other[right_key];
+        assert_eq!(
+            lk.dtype, rk.dtype,
+            "Key columns should have same dtype ({} vs {})",
+            lk.dtype, rk.dtype
+        );
+        let pairs = dtype_match!(lk.dtype, lk.to_vec(), |x| join_indices(x, rk.to_vec()); Vec);
+        let (li, ri): (Vec<usize>, Vec<usize>) = pairs.into_iter().unzip();
+
+        let mut df = DataFrame::new(vec![]);
+        for (h, s) in self.iter_columns() {
+            df.push(h, s.select_indices(&li));
+        }
+        for (h, s) in other.iter_columns() {
+            if h == right_key {
+                continue;
+            }
+            if df.ics.iter().any(|x| x == h) {
+                df.push(&format!("{}_right", h), s.select_indices(&ri));
+            } else {
+                df.push(h, s.select_indices(&ri));
+            }
+        }
+        df
+    }
 }
 
 impl Index<&str> for DataFrame {
@@ -1698,6 +2009,108 @@ impl WithCSV for DataFrame {
     }
 }
 
+/// To handle JSON format
+///
+/// JSON has no `NaN` or infinity, so non-finite floats are written as
+/// `"NaN"`, `"inf"` and `"-inf"`.
+#[cfg(feature = "serde")]
+pub trait WithJSON: Sized {
+    fn to_json(&self) -> String;
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>>;
+}
+
+/// Serde helper for float vectors
+///
+/// In human-readable formats (e.g. JSON) non-finite values are written as
+/// `"NaN"`, `"inf"` and `"-inf"` instead of `null`. Other formats are unchanged.
+#[cfg(feature = "serde")]
+pub(crate) mod non_finite_serde {
+    use num_traits::Float;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(untagged)]
+    enum Ser<T> {
+        Num(T),
+        Str(&'static str),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum De<T> {
+        Num(T),
+        Str(String),
+    }
+
+    pub fn serialize<S, T>(v: &[T], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Float + Serialize,
+    {
+        if !s.is_human_readable() {
+            return v.serialize(s);
+        }
+        s.collect_seq(v.iter().map(|&x| match x {
+            x if x.is_finite() => Ser::Num(x),
+            x if x.is_nan() => Ser::Str("NaN"),
+            x if x > T::zero() => Ser::Str("inf"),
+            _ => Ser::Str("-inf"),
+        }))
+    }
+
+    pub fn deserialize<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Float + Deserialize<'de>,
+    {
+        if !d.is_human_readable() {
+            return Vec::<T>::deserialize(d);
+        }
+        Vec::<De<T>>::deserialize(d)?
+            .into_iter()
+            .map(|x| match x {
+                De::Num(x) => Ok(x),
+                De::Str(s) => match s.as_str() {
+                    "NaN" => Ok(T::nan()),
+                    "inf" => Ok(T::infinity()),
+                    "-inf" => Ok(T::neg_infinity()),
+                    _ => Err(D::Error::custom(format!("invalid float: {:?}", s))),
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl WithJSON for DataFrame {
+    /// DataFrame to JSON string
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Can't serialize DataFrame")
+    }
+
+    /// DataFrame from JSON string
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>> {
+        let df: DataFrame = serde_json::from_str(s)?;
+        if df.ics.len() != df.data.len() {
+            return Err(format!(
+                "Header length ({}) is different from the number of columns ({})",
+                df.ics.len(),
+                df.data.len()
+            ).into());
+        }
+        for (h, s) in df.iter_columns() {
+            if s.dtype != s.values.dtype() {
+                return Err(format!(
+                    "dtype of column '{}' ({}) does not match its values ({})",
+                    h, s.dtype, s.values.dtype()
+                ).into());
+            }
+        }
+        Ok(df)
+    }
+}
+
 /// To handle with NetCDF file format
 #[cfg(feature= "nc")]
 pub trait WithNetCDF: Sized {
@@ -0,0 +1,502 @@
+//! Interval arithmetic
+//!
+//! ## Description
+//!
+//! * `Interval { lo, hi }` represents the closed set $[lo, hi]$.
+//! * Every operation rounds outward, so the result always encloses the exact value
+//!   for any choice of operands in the input intervals.
+//! * `+, -, *, /` and `sqrt` are rounded outward only when the floating point result is inexact
+//!   (detected by error-free transformations), so enclosures are as tight as possible.
+//!   Results of `*, /, sqrt` within `2^53` of the subnormal range are always widened by one ulp,
+//!   since the error term itself may underflow to zero.
+//! * `exp`, `ln`, `sin`, `cos` are widened by one ulp on both sides
+//!   (assuming the math library is accurate within one ulp).
+//! * Division by an interval containing zero gives the entire real line.
+//! * `Interval` implements `MatrixScalar`, so `Matrix<Interval>` supports `+, -, *`.
+//!   Intervals are only partially ordered: `x < y` iff `x.hi < y.lo`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use peroxide::fuga::*;
+//!
+//! let x = Interval::new(1f64, 2f64);
+//! let y = Interval::new(-1f64, 3f64);
+//! assert_eq!(x + y, Interval::new(0f64, 5f64));
+//! assert_eq!(x * y, Interval::new(-2f64, 6f64));
+//!
+//! let third = Interval::point(1f64) / Interval::point(3f64);
+//! assert!(third.lo < 1f64 / 3f64 + 1e-16 && 1f64 / 3f64 - 1e-16 < third.hi);
+//! println!("{}", third); // [0.3333333333333333, 0.33333333333333337]
+//! ```
+
+use crate::traits::num::MatrixScalar;
+use core::cmp::Ordering;
+use core::f64::consts::{PI, TAU};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Closed interval $[lo, hi]$
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Interval {
+    /// Create interval $[lo, hi]$
+    ///
+    /// # Panics
+    /// If `lo > hi` or either bound is `NaN`.
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "Invalid interval: [{}, {}]", lo, hi);
+        Interval { lo, hi }
+    }
+
+    /// Degenerate interval $[x, x]$
+    pub fn point(x: f64) -> Self {
+        Self::new(x, x)
+    }
+
+    /// Entire real line $[-\infty, \infty]$
+    pub fn entire() -> Self {
+        Interval {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+
+    /// Width `hi - lo` (rounded up)
+    pub fn width(&self) -> f64 {
+        let (r, e) = two_sum(self.hi, -self.lo);
+        round_up(r, e)
+    }
+
+    /// Midpoint
+    pub fn mid(&self) -> f64 {
+        self.lo / 2f64 + self.hi / 2f64
+    }
+
+    /// Check whether `x` is in the interval
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Square root
+    ///
+    /// # Panics
+    /// If `lo < 0`
+    pub fn sqrt(&self) -> Self {
+        assert!(
+            self.lo >= 0f64,
+            "sqrt is defined for non-negative intervals"
+        );
+        let down = |x: f64| {
+            let s = x.sqrt();
+            if x != 0f64 && is_tiny(x) {
+                next_down(s)
+            } else {
+                round_down(s, (-s).mul_add(s, x))
+            }
+        };
+        let up = |x: f64| {
+            let s = x.sqrt();
+            if x != 0f64 && is_tiny(x) {
+                next_up(s)
+            } else {
+                round_up(s, (-s).mul_add(s, x))
+            }
+        };
+        Interval {
+            lo: down(self.lo),
+            hi: up(self.hi),
+        }
+    }
+
+    /// Integer power
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let x = Interval::new(-2f64, 1f64);
+    /// assert_eq!(x.powi(2), Interval::new(0f64, 4f64));
+    /// assert_eq!(x.powi(3), Interval::new(-8f64, 1f64));
+    /// ```
+    pub fn powi(&self, n: i32) -> Self {
+        if n < 0 {
+            return Interval::point(1f64) / self.powi(-n);
+        }
+        let n = n as u32;
+        if n == 0 {
+            return Interval::point(1f64);
+        }
+        // |x|^n with directed rounding (|x| >= 0)
+        let pow_down = |x: f64| (1..n).fold(x, |acc, _| mul_down(acc, x));
+        let pow_up = |x: f64| (1..n).fold(x, |acc, _| mul_up(acc, x));
+        let signed = |x: f64, up: bool| {
+            if x >= 0f64 {
+                if up {
+                    pow_up(x)
+                } else {
+                    pow_down(x)
+                }
+            } else if up {
+                -pow_down(-x)
+            } else {
+                -pow_up(-x)
+            }
+        };
+        if n % 2 == 1 {
+            Interval {
+                lo: signed(self.lo, false),
+                hi: signed(self.hi, true),
+            }
+        } else {
+            let (a, b) = (self.lo.abs(), self.hi.abs());
+            let lo = if self.contains(0f64) {
+                0f64
+            } else {
+                pow_down(a.min(b))
+            };
+            Interval {
+                lo,
+                hi: pow_up(a.max(b)),
+            }
+        }
+    }
+
+    /// Exponential
+    pub fn exp(&self) -> Self {
+        Interval {
+            lo: next_down(self.lo.exp()).max(0f64),
+            hi: next_up(self.hi.exp()),
+        }
+    }
+
+    /// Natural logarithm
+    ///
+    /// # Panics
+    /// If `lo <= 0`
+    pub fn ln(&self) -> Self {
+        assert!(self.lo > 0f64, "ln is defined for positive intervals");
+        Interval {
+            lo: next_down(self.lo.ln()),
+            hi: next_up(self.hi.ln()),
+        }
+    }
+
+    /// Sine
+    pub fn sin(&self) -> Self {
+        self.periodic(f64::sin, PI / 2f64, -PI / 2f64)
+    }
+
+    /// Cosine
+    pub fn cos(&self) -> Self {
+        self.periodic(f64::cos, 0f64, PI)
+    }
+
+    /// Enclosure of a $2\pi$-periodic function in `[-1, 1]` with maxima at `max_at + 2k\pi`
+    /// and minima at `min_at + 2k\pi`
+    fn periodic<F: Fn(f64) -> f64>(&self, f: F, max_at: f64, min_at: f64) -> Self {
+        if self.hi - self.lo >= TAU {
+            return Interval::new(-1f64, 1f64);
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut lo = next_down(a.min(b)).max(-1f64);
+        let mut hi = next_up(a.max(b)).min(1f64);
+        if self.hits(max_at) {
+            hi = 1f64;
+        }
+        if self.hits(min_at) {
+            lo = -1f64;
+        }
+        Interval { lo, hi }
+    }
+
+    /// Check whether `offset + 2k\pi` is (possibly) in the interval for some integer `k`
+    fn hits(&self, offset: f64) -> bool {
+        // Slack covers the rounding error of `2k\pi`, so extrema are never missed
+        let slack = 4f64 * f64::EPSILON * self.lo.abs().max(self.hi.abs()).max(1f64);
+        let k = ((self.lo - slack - offset) / TAU).ceil();
+        offset + k * TAU <= self.hi + slack
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (l, el) = two_sum(self.lo, rhs.lo);
+        let (h, eh) = two_sum(self.hi, rhs.hi);
+        Interval {
+            lo: round_down(l, el),
+            hi: round_up(h, eh),
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let pairs = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        let lo = pairs
+            .iter()
+            .map(|&(a, b)| mul_down(a, b))
+            .fold(f64::INFINITY, f64::min);
+        let hi = pairs
+            .iter()
+            .map(|&(a, b)| mul_up(a, b))
+            .fold(f64::NEG_INFINITY, f64::max);
+        Interval { lo, hi }
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(0f64) {
+            return Interval::entire();
+        }
+        let pairs = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        let lo = pairs
+            .iter()
+            .map(|&(a, b)| div_down(a, b))
+            .fold(f64::INFINITY, f64::min);
+        let hi = pairs
+            .iter()
+            .map(|&(a, b)| div_up(a, b))
+            .fold(f64::NEG_INFINITY, f64::max);
+        Interval { lo, hi }
+    }
+}
+
+macro_rules! impl_scalar_ops {
+    ($($trait:ident, $fn:ident);*) => {
+        $(
+            impl $trait<f64> for Interval {
+                type Output = Self;
+
+                fn $fn(self, rhs: f64) -> Self::Output {
+                    self.$fn(Interval::point(rhs))
+                }
+            }
+
+            impl $trait<Interval> for f64 {
+                type Output = Interval;
+
+                fn $fn(self, rhs: Interval) -> Self::Output {
+                    Interval::point(self).$fn(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_ops!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl From<f64> for Interval {
+    fn from(x: f64) -> Self {
+        Interval::point(x)
+    }
+}
+
+/// Certainly less or greater, `None` for distinct overlapping intervals
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Interval::point(0f64)
+    }
+}
+
+impl MatrixScalar for Interval {
+    fn zero() -> Self {
+        Interval::point(0f64)
+    }
+
+    fn one() -> Self {
+        Interval::point(1f64)
+    }
+}
+
+// =============================================================================
+// Directed rounding
+// =============================================================================
+/// Smallest float larger than `x`
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0f64 {
+        return f64::from_bits(1);
+    }
+    let bits = x.to_bits();
+    if x > 0f64 {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+/// Largest float smaller than `x`
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+/// Rounded result `r` and (sign of) its error `exact - r`
+fn round_down(r: f64, err: f64) -> f64 {
+    if err < 0f64 {
+        next_down(r)
+    } else {
+        r
+    }
+}
+
+fn round_up(r: f64, err: f64) -> f64 {
+    if err > 0f64 {
+        next_up(r)
+    } else {
+        r
+    }
+}
+
+/// Error of an overflowed result (exact value is finite)
+fn overflow_err(r: f64, finite_inputs: bool) -> f64 {
+    if r.is_infinite() && finite_inputs {
+        -r.signum()
+    } else {
+        0f64
+    }
+}
+
+/// `a + b = s + e` exactly (Knuth's TwoSum)
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    if !s.is_finite() {
+        return (s, overflow_err(s, a.is_finite() && b.is_finite()));
+    }
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Below `2^-969`, error terms computed by `mul_add` may underflow
+fn is_tiny(x: f64) -> bool {
+    x.abs() < f64::MIN_POSITIVE * 2f64.powi(53)
+}
+
+/// `a * b = p + e` exactly (`0 * inf` is taken as `0`) unless `p` is tiny
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    if a == 0f64 || b == 0f64 {
+        return (0f64, 0f64);
+    }
+    let p = a * b;
+    if !p.is_finite() {
+        return (p, overflow_err(p, a.is_finite() && b.is_finite()));
+    }
+    (p, a.mul_add(b, -p))
+}
+
+/// `a / b = q + e` with the sign of `e` exact unless `a` or `q` is tiny
+fn two_div(a: f64, b: f64) -> (f64, f64) {
+    let q = a / b;
+    if !q.is_finite() {
+        return (q, overflow_err(q, a.is_finite()));
+    }
+    if b.is_infinite() || a.is_infinite() {
+        return (q, 0f64);
+    }
+    // a - q * b is exact, and (a - q * b) / b has the sign of the error
+    let r = (-q).mul_add(b, a);
+    (q, r * b.signum())
+}
+
+fn mul_down(a: f64, b: f64) -> f64 {
+    let (p, e) = two_prod(a, b);
+    if a != 0f64 && b != 0f64 && is_tiny(p) {
+        next_down(p)
+    } else {
+        round_down(p, e)
+    }
+}
+
+fn mul_up(a: f64, b: f64) -> f64 {
+    let (p, e) = two_prod(a, b);
+    if a != 0f64 && b != 0f64 && is_tiny(p) {
+        next_up(p)
+    } else {
+        round_up(p, e)
+    }
+}
+
+fn div_down(a: f64, b: f64) -> f64 {
+    let (q, e) = two_div(a, b);
+    if a != 0f64 && b.is_finite() && (is_tiny(a) || is_tiny(q)) {
+        next_down(q)
+    } else {
+        round_down(q, e)
+    }
+}
+
+fn div_up(a: f64, b: f64) -> f64 {
+    let (q, e) = two_div(a, b);
+    if a != 0f64 && b.is_finite() && (is_tiny(a) || is_tiny(q)) {
+        next_up(q)
+    } else {
+        round_up(q, e)
+    }
+}
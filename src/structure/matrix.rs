@@ -614,25 +614,43 @@ use lapack::{dgecon, dgeqrf, dgetrf, dgetri, dgetrs, dorgqr, dgesvd, dpotrf};
 use serde::{Deserialize, Serialize};
 
 pub use self::Shape::{Col, Row};
-use crate::numerical::eigen::{eigen, EigenMethod};
+use crate::numerical::eigen::{eigen, random_unit_vector, real_eigenvalues, EigenMethod};
+#[cfg(feature = "std")]
+use crate::special::function::softplus;
 use crate::traits::{
     general::Algorithm,
     fp::{FPMatrix, FPVector},
+    num::MatrixScalar,
     math::{InnerProduct, LinearOp, MatrixProduct, Norm, Normed, Vector},
     mutable::MutMatrix,
 };
+#[cfg(feature = "std")]
+use crate::traits::math::StableExpLogOps;
 use crate::util::{
     low_level::{swap_vec_ptr, copy_vec_ptr},
-    non_macro::{cbind, eye, rbind, zeros},
+    non_macro::{cbind, eye, rbind, zeros, zeros_shape, ConcatenateError},
     useful::{nearly_eq, tab},
 };
+#[cfg(feature = "std")]
 use crate::structure::dataframe::{Series, TypedVector};
-use std::cmp::{max, min};
+use crate::structure::interval::Interval;
+#[cfg(feature = "serde")]
+use crate::structure::dataframe::WithJSON;
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+use core::cmp::{max, min};
+use core::convert::TryInto;
+#[cfg(feature = "std")]
 pub use std::error::Error;
-use std::fmt;
-use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+#[cfg(not(feature = "std"))]
+pub use core::error::Error;
+use core::fmt;
+use core::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 use crate::traits::sugar::ScalableMut;
+#[cfg(feature = "std")]
 use peroxide_num::{ExpLogOps, PowOps, TrigOps, Numeric};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use anyhow::bail;
 
 pub type Perms = Vec<(usize, usize)>;
 
@@ -669,8 +687,25 @@ impl fmt::Display for Shape {
     }
 }
 
+/// Direction of concatenation for `Matrix::concat`
+///
+/// * `Axis::Row` - stack along rows (like `rbind`, number of columns should match)
+/// * `Axis::Col` - stack along columns (like `cbind`, number of rows should match)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
 /// R-like matrix structure
 ///
+/// # Description
+///
+/// `Matrix` is generic over its element type `T: MatrixScalar` (default: `f64`).
+/// Thus, `Matrix` is same as `Matrix<f64>`.
+/// Linear algebra & mathematical operations are only implemented for `Matrix<f64>`,
+/// while other scalars (`f32`, integers) support indexing, shape manipulation and element-wise arithmetic.
+///
 /// # Examples
 ///
 /// ```
@@ -682,11 +717,14 @@ impl fmt::Display for Shape {
 ///     col: 2,
 ///     shape: Row,
 /// }; // [[1,2],[3,4]]
+///
+/// let b: Matrix<i32> = Matrix::from_vec(vec![1,2,3,4], 2, 2, Row);
+/// assert_eq!(b[(1,0)], 3);
 /// ```
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Matrix {
-    pub data: Vec<f64>,
+pub struct Matrix<T = f64> {
+    pub data: Vec<T>,
     pub row: usize,
     pub col: usize,
     pub shape: Shape,
@@ -815,17 +853,66 @@ impl PartialEq for Matrix {
     }
 }
 
-/// Main matrix structure
+/// Shape & index related methods for generic matrix
 #[allow(dead_code)]
-impl Matrix {
+impl<T: MatrixScalar> Matrix<T> {
+    /// Generic matrix constructor
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = Matrix::from_vec(vec![1u8, 2, 3, 4], 2, 2, Col);
+    /// assert_eq!(a.row(0), vec![1u8, 3]);
+    /// ```
+    pub fn from_vec(data: Vec<T>, row: usize, col: usize, shape: Shape) -> Self {
+        assert_eq!(data.len(), row * col, "Length of data should be equal to row * col");
+        Matrix {
+            data,
+            row,
+            col,
+            shape,
+        }
+    }
+
+    /// Matrix from a function of indices (`Row` shape)
+    ///
+    /// # Description
+    /// Entry `(i, j)` is `f(i, j)`. If `r` or `c` is zero, `f` is never called.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// // Hilbert matrix
+    /// let h = Matrix::from_fn(3, 3, |i, j| 1f64 / ((i + j + 1) as f64));
+    /// assert_eq!(h[(0, 0)], 1f64);
+    /// assert_eq!(h[(1, 2)], 0.25);
+    /// assert_eq!(h, h.t());
+    /// ```
+    pub fn from_fn<F: Fn(usize, usize) -> T>(r: usize, c: usize, f: F) -> Self {
+        let mut data = Vec::with_capacity(r * c);
+        for i in 0..r {
+            for j in 0..c {
+                data.push(f(i, j));
+            }
+        }
+        Matrix {
+            data,
+            row: r,
+            col: c,
+            shape: Shape::Row,
+        }
+    }
+
     /// Raw pointer for `self.data`
-    pub fn ptr(&self) -> *const f64 {
-        &self.data[0] as *const f64
+    pub fn ptr(&self) -> *const T {
+        &self.data[0] as *const T
     }
 
     /// Raw mutable pointer for `self.data`
-    pub fn mut_ptr(&mut self) -> *mut f64 {
-        &mut self.data[0] as *mut f64
+    pub fn mut_ptr(&mut self) -> *mut T {
+        &mut self.data[0] as *mut T
     }
 
     /// Slice of `self.data`
@@ -838,7 +925,7 @@ impl Matrix {
     /// let b = a.as_slice();
     /// assert_eq!(b, &[1f64,2f64,3f64,4f64]);
     /// ```
-    pub fn as_slice(&self) -> &[f64] {
+    pub fn as_slice(&self) -> &[T] {
         &self.data[..]
     }
 
@@ -854,7 +941,7 @@ impl Matrix {
     /// assert_eq!(b, &[5f64, 2f64, 3f64, 4f64]);
     /// assert_eq!(a, matrix(vec![5,2,3,4], 2, 2, Col));
     /// ```
-    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
         &mut self.data[..]
     }
 
@@ -876,7 +963,7 @@ impl Matrix {
         let c = self.col;
         assert_eq!(r * c, self.data.len());
         let l = r * c - 1;
-        let mut data: Vec<f64> = self.data.clone();
+        let mut data: Vec<T> = self.data.clone();
         let ref_data = &self.data;
 
         match self.shape {
@@ -886,7 +973,7 @@ impl Matrix {
                     data[i] = ref_data[s];
                 }
                 data[l] = ref_data[l];
-                matrix(data, r, c, Col)
+                Matrix::from_vec(data, r, c, Col)
             }
             Col => {
                 for i in 0..l {
@@ -894,7 +981,7 @@ impl Matrix {
                     data[i] = ref_data[s];
                 }
                 data[l] = ref_data[l];
-                matrix(data, r, c, Row)
+                Matrix::from_vec(data, r, c, Row)
             }
         }
     }
@@ -939,93 +1026,6 @@ impl Matrix {
         }
     }
 
-    /// Spread data(1D vector) to 2D formatted String
-    ///
-    /// # Examples
-    /// ```
-    /// use peroxide::fuga::*;
-    ///
-    /// let a = matrix(vec![1,2,3,4],2,2,Row);
-    /// println!("{}", a.spread()); // same as println!("{}", a);
-    /// // Result:
-    /// //       c[0] c[1]
-    /// // r[0]     1    3
-    /// // r[1]     2    4
-    /// ```
-    pub fn spread(&self) -> String {
-        assert_eq!(self.row * self.col, self.data.len());
-        let r = self.row;
-        let c = self.col;
-        let mut key_row = 20usize;
-        let mut key_col = 20usize;
-
-        if r > 100 || c > 100 || (r > 20 && c > 20) {
-            let part = if r <= 10 {
-                key_row = r;
-                key_col = 100;
-                self.take_col(100)
-            } else if c <= 10 {
-                key_row = 100;
-                key_col = c;
-                self.take_row(100)
-            } else {
-                self.take_row(20).take_col(20)
-            };
-            return format!(
-                "Result is too large to print - {}x{}\nonly print {}x{} parts:\n{}",
-                self.row.to_string(),
-                self.col.to_string(),
-                key_row.to_string(),
-                key_col.to_string(),
-                part.spread()
-            );
-        }
-
-        // Find maximum length of data
-        let sample = self.data.clone();
-        let mut space: usize = sample
-            .into_iter()
-            .map(
-                |x| min(format!("{:.4}", x).len(), x.to_string().len()), // Choose minimum of approx vs normal
-            )
-            .fold(0, |x, y| max(x, y))
-            + 1;
-
-        if space < 5 {
-            space = 5;
-        }
-
-        let mut result = String::new();
-
-        result.push_str(&tab("", 5));
-        for i in 0..c {
-            result.push_str(&tab(&format!("c[{}]", i), space)); // Header
-        }
-        result.push('\n');
-
-        for i in 0..r {
-            result.push_str(&tab(&format!("r[{}]", i), 5));
-            for j in 0..c {
-                let st1 = format!("{:.4}", self[(i, j)]); // Round at fourth position
-                let st2 = self[(i, j)].to_string(); // Normal string
-                let mut st = st2.clone();
-
-                // Select more small thing
-                if st1.len() < st2.len() {
-                    st = st1;
-                }
-
-                result.push_str(&tab(&st, space));
-            }
-            if i == (r - 1) {
-                break;
-            }
-            result.push('\n');
-        }
-
-        return result;
-    }
-
     /// Extract Column
     ///
     /// # Examples
@@ -1039,9 +1039,9 @@ impl Matrix {
     ///     assert_eq!(a.col(0), c!(1,3));
     /// }
     /// ```
-    pub fn col(&self, index: usize) -> Vec<f64> {
+    pub fn col(&self, index: usize) -> Vec<T> {
         assert!(index < self.col);
-        let mut container: Vec<f64> = vec![0f64; self.row];
+        let mut container: Vec<T> = vec![T::default(); self.row];
         for i in 0..self.row {
             container[i] = self[(i, index)];
         }
@@ -1061,9 +1061,9 @@ impl Matrix {
     ///     assert_eq!(a.row(0), c!(1,2));
     /// }
     /// ```
-    pub fn row(&self, index: usize) -> Vec<f64> {
+    pub fn row(&self, index: usize) -> Vec<T> {
         assert!(index < self.row);
-        let mut container: Vec<f64> = vec![0f64; self.col];
+        let mut container: Vec<T> = vec![T::default(); self.col];
         for i in 0..self.col {
             container[i] = self[(index, i)];
         }
@@ -1083,8 +1083,8 @@ impl Matrix {
     ///     assert_eq!(a.diag(), c!(1,4));
     /// }
     /// ```
-    pub fn diag(&self) -> Vec<f64> {
-        let mut container = vec![0f64; self.row];
+    pub fn diag(&self) -> Vec<T> {
+        let mut container = vec![T::default(); self.row];
         let r = self.row;
         let c = self.col;
         assert_eq!(r, c);
@@ -1106,8 +1106,8 @@ impl Matrix {
     /// ```
     pub fn transpose(&self) -> Self {
         match self.shape {
-            Row => matrix(self.data.clone(), self.col, self.row, Col),
-            Col => matrix(self.data.clone(), self.col, self.row, Row),
+            Row => Matrix::from_vec(self.data.clone(), self.col, self.row, Col),
+            Col => Matrix::from_vec(self.data.clone(), self.col, self.row, Row),
         }
     }
 
@@ -1128,6 +1128,149 @@ impl Matrix {
         self.transpose()
     }
 
+    /// Swap two rows in place
+    ///
+    /// # Panics
+    /// If `i` or `j` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = ml_matrix("1 2;3 4;5 6");
+    /// a.swap_rows(0, 2);
+    /// assert_eq!(a, ml_matrix("5 6;3 4;1 2"));
+    /// ```
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        assert!(i < self.row, "Row index {} is out of range (rows: {})", i, self.row);
+        assert!(j < self.row, "Row index {} is out of range (rows: {})", j, self.row);
+        if i == j {
+            return;
+        }
+        for k in 0..self.col {
+            let tmp = self[(i, k)];
+            self[(i, k)] = self[(j, k)];
+            self[(j, k)] = tmp;
+        }
+    }
+
+    /// Swap two columns in place
+    ///
+    /// # Panics
+    /// If `i` or `j` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = ml_matrix("1 2 3;4 5 6");
+    /// a.swap_cols(0, 1);
+    /// assert_eq!(a, ml_matrix("2 1 3;5 4 6"));
+    /// ```
+    pub fn swap_cols(&mut self, i: usize, j: usize) {
+        assert!(i < self.col, "Column index {} is out of range (cols: {})", i, self.col);
+        assert!(j < self.col, "Column index {} is out of range (cols: {})", j, self.col);
+        if i == j {
+            return;
+        }
+        for k in 0..self.row {
+            let tmp = self[(k, i)];
+            self[(k, i)] = self[(k, j)];
+            self[(k, j)] = tmp;
+        }
+    }
+}
+
+/// Main matrix structure
+#[allow(dead_code)]
+impl Matrix {
+    /// Spread data(1D vector) to 2D formatted String
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = matrix(vec![1,2,3,4],2,2,Row);
+    /// println!("{}", a.spread()); // same as println!("{}", a);
+    /// // Result:
+    /// //       c[0] c[1]
+    /// // r[0]     1    3
+    /// // r[1]     2    4
+    /// ```
+    pub fn spread(&self) -> String {
+        assert_eq!(self.row * self.col, self.data.len());
+        let r = self.row;
+        let c = self.col;
+        let mut key_row = 20usize;
+        let mut key_col = 20usize;
+
+        if r > 100 || c > 100 || (r > 20 && c > 20) {
+            let part = if r <= 10 {
+                key_row = r;
+                key_col = 100;
+                self.take_col(100)
+            } else if c <= 10 {
+                key_row = 100;
+                key_col = c;
+                self.take_row(100)
+            } else {
+                self.take_row(20).take_col(20)
+            };
+            return format!(
+                "Result is too large to print - {}x{}\nonly print {}x{} parts:\n{}",
+                self.row.to_string(),
+                self.col.to_string(),
+                key_row.to_string(),
+                key_col.to_string(),
+                part.spread()
+            );
+        }
+
+        // Find maximum length of data
+        let sample = self.data.clone();
+        let mut space: usize = sample
+            .into_iter()
+            .map(
+                |x| min(format!("{:.4}", x).len(), x.to_string().len()), // Choose minimum of approx vs normal
+            )
+            .fold(0, |x, y| max(x, y))
+            + 1;
+
+        if space < 5 {
+            space = 5;
+        }
+
+        let mut result = String::new();
+
+        result.push_str(&tab("", 5));
+        for i in 0..c {
+            result.push_str(&tab(&format!("c[{}]", i), space)); // Header
+        }
+        result.push('\n');
+
+        for i in 0..r {
+            result.push_str(&tab(&format!("r[{}]", i), 5));
+            for j in 0..c {
+                let st1 = format!("{:.4}", self[(i, j)]); // Round at fourth position
+                let st2 = self[(i, j)].to_string(); // Normal string
+                let mut st = st2.clone();
+
+                // Select more small thing
+                if st1.len() < st2.len() {
+                    st = st1;
+                }
+
+                result.push_str(&tab(&st, space));
+            }
+            if i == (r - 1) {
+                break;
+            }
+            result.push('\n');
+        }
+
+        return result;
+    }
+
     /// Write to CSV
     ///
     /// # Examples
@@ -1321,44 +1464,1067 @@ impl Matrix {
         }
     }
 
-    /// From index operations
-    pub fn from_index<F, G>(f: F, size: (usize, usize)) -> Matrix
-    where
-        F: Fn(usize, usize) -> G + Copy,
-        G: Into<f64>,
-    {
-        let row = size.0;
-        let col = size.1;
-
-        let mut mat = matrix(vec![0f64; row * col], row, col, Row);
+    /// From index operations
+    pub fn from_index<F, G>(f: F, size: (usize, usize)) -> Matrix
+    where
+        F: Fn(usize, usize) -> G + Copy,
+        G: Into<f64>,
+    {
+        let row = size.0;
+        let col = size.1;
+
+        let mut mat = matrix(vec![0f64; row * col], row, col, Row);
+
+        for i in 0..row {
+            for j in 0..col {
+                mat[(i, j)] = f(i, j).into();
+            }
+        }
+        mat
+    }
+
+    /// Matrix to `Vec<Vec<f64>>`
+    ///
+    /// To send `Matrix` to `inline-python`
+    pub fn to_vec(&self) -> Vec<Vec<f64>> {
+        let mut result = vec![vec![0f64; self.col]; self.row];
+        for i in 0..self.row {
+            result[i] = self.row(i);
+        }
+        result
+    }
+
+    pub fn to_diag(&self) -> Matrix {
+        assert_eq!(self.row, self.col, "Should be square matrix");
+        let mut result = matrix(vec![0f64; self.row * self.col], self.row, self.col, Row);
+        let diag = self.diag();
+        for i in 0..self.row {
+            result[(i, i)] = diag[i];
+        }
+        result
+    }
+
+    /// Fill main diagonal with a value
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = zeros(2, 2);
+    /// a.fill_diagonal(3f64);
+    /// assert_eq!(a, ml_matrix("3 0;0 3"));
+    /// ```
+    pub fn fill_diagonal(&mut self, value: f64) {
+        for i in 0..min(self.row, self.col) {
+            self[(i, i)] = value;
+        }
+    }
+
+    /// Apply function to each element (shape is preserved)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// assert_eq!(a.map(|x| x * x), ml_matrix("1 4;9 16"));
+    /// ```
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Matrix {
+        matrix(
+            self.data.iter().map(|x| f(*x)).collect(),
+            self.row,
+            self.col,
+            self.shape,
+        )
+    }
+
+    /// Element-wise binary operation with other matrix
+    ///
+    /// # Description
+    /// Same as `FPMatrix::zip_with` with the order of arguments swapped,
+    /// but dimensions are checked.
+    ///
+    /// # Panics
+    /// If dimensions of two matrices are different.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 5;3 2");
+    /// let b = ml_matrix("4 2;3 6");
+    /// assert_eq!(a.zip_map(&b, |x, y| x.max(y)), ml_matrix("4 5;3 6"));
+    /// ```
+    pub fn zip_map<F: Fn(f64, f64) -> f64>(&self, other: &Matrix, f: F) -> Matrix {
+        assert!(
+            self.row == other.row && self.col == other.col,
+            "Matrix dimensions should be equal ({}x{} vs {}x{})",
+            self.row,
+            self.col,
+            other.row,
+            other.col
+        );
+        let data = if self.shape == other.shape {
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(x, y)| f(*x, *y))
+                .collect()
+        } else {
+            let o = other.change_shape();
+            self.data
+                .iter()
+                .zip(o.data.iter())
+                .map(|(x, y)| f(*x, *y))
+                .collect()
+        };
+        matrix(data, self.row, self.col, self.shape)
+    }
+
+    /// Kronecker product with identity from the left ($I_n \otimes A$)
+    ///
+    /// # Description
+    /// Block diagonal matrix with `n` copies of `self`.
+    /// Equivalent to `eye(n).kronecker(self)` without intermediate allocations.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// assert_eq!(a.kron_identity(2), eye(2).kronecker(&a));
+    /// ```
+    pub fn kron_identity(&self, n: usize) -> Matrix {
+        let (r, c) = (self.row, self.col);
+        let mut m = zeros(n * r, n * c);
+        for k in 0..n {
+            for i in 0..r {
+                for j in 0..c {
+                    m[(k * r + i, k * c + j)] = self[(i, j)];
+                }
+            }
+        }
+        m
+    }
+
+    /// Companion matrix of polynomial
+    ///
+    /// # Description
+    /// `coeffs` are in descending order of degree (same as `Polynomial`),
+    /// i.e. $a_0 x^n + a_1 x^{n-1} + \cdots + a_n$.
+    /// The polynomial is normalized by $a_0$, and the eigenvalues of the
+    /// resulting $n \times n$ matrix are the roots of the polynomial.
+    ///
+    /// # Panics
+    /// If `coeffs` has less than two elements or the leading coefficient is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// // 2x^2 - 6x + 4 = 2(x - 1)(x - 2)
+    /// let c = Matrix::companion(&[2f64, -6f64, 4f64]);
+    /// assert_eq!(c, ml_matrix("3 -2;1 0"));
+    /// ```
+    pub fn companion(coeffs: &[f64]) -> Matrix {
+        assert!(coeffs.len() >= 2, "Polynomial should have degree at least 1");
+        assert!(coeffs[0] != 0f64, "Leading coefficient should be nonzero");
+        let n = coeffs.len() - 1;
+        let mut m = zeros(n, n);
+        for j in 0..n {
+            m[(0, j)] = -coeffs[j + 1] / coeffs[0];
+        }
+        for i in 1..n {
+            m[(i, i - 1)] = 1f64;
+        }
+        m
+    }
+
+    /// Concatenate any number of matrices along an axis
+    ///
+    /// # Description
+    /// * `Axis::Row` : result is `Row` shaped with `sum(rows) x col`
+    /// * `Axis::Col` : result is `Col` shaped with `row x sum(cols)`
+    ///
+    /// Returns `ConcatenateError` if `mats` is empty or the other dimension differs.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2");
+    /// let b = ml_matrix("3 4;5 6");
+    /// assert_eq!(Matrix::concat(&[a.clone(), b.clone()], Axis::Row).unwrap(), ml_matrix("1 2;3 4;5 6"));
+    /// assert_eq!(Matrix::concat(&[b.t(), a.t()], Axis::Col).unwrap(), ml_matrix("3 5 1;4 6 2"));
+    /// assert!(Matrix::concat(&[a, ml_matrix("1 2 3")], Axis::Row).is_err());
+    /// ```
+    pub fn concat(mats: &[Matrix], axis: Axis) -> anyhow::Result<Matrix> {
+        if mats.is_empty() {
+            bail!(ConcatenateError::Empty);
+        }
+        let shape = match axis {
+            Axis::Row => Row,
+            Axis::Col => Col,
+        };
+        let (fixed, stacked): (Vec<usize>, Vec<usize>) = match axis {
+            Axis::Row => mats.iter().map(|m| (m.col, m.row)).unzip(),
+            Axis::Col => mats.iter().map(|m| (m.row, m.col)).unzip(),
+        };
+        if fixed.iter().any(|&x| x != fixed[0]) {
+            bail!(ConcatenateError::DifferentLength);
+        }
+
+        let mut data = Vec::with_capacity(mats.iter().map(|m| m.data.len()).sum());
+        for m in mats {
+            if m.shape == shape {
+                data.extend_from_slice(&m.data);
+            } else {
+                data.extend(m.change_shape().data);
+            }
+        }
+        let total = stacked.iter().sum();
+        Ok(match axis {
+            Axis::Row => matrix(data, total, fixed[0], Row),
+            Axis::Col => matrix(data, fixed[0], total, Col),
+        })
+    }
+
+    /// Split matrix into pieces of given sizes along an axis (inverse of `concat`)
+    ///
+    /// # Description
+    /// * `Axis::Row` : pieces of `sizes[i]` rows (`Row` shaped)
+    /// * `Axis::Col` : pieces of `sizes[i]` columns (`Col` shaped)
+    ///
+    /// # Panics
+    /// If `sizes` does not sum to the length of the axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2 3;4 5 6;7 8 9");
+    /// let v = a.split(Axis::Row, &[1, 2]);
+    /// assert_eq!(v[0], ml_matrix("1 2 3"));
+    /// assert_eq!(v[1], ml_matrix("4 5 6;7 8 9"));
+    ///
+    /// let w = a.split(Axis::Col, &[2, 1]);
+    /// assert_eq!(w[1], ml_matrix("3;6;9"));
+    /// ```
+    pub fn split(&self, axis: Axis, sizes: &[usize]) -> Vec<Matrix> {
+        let (len, other, shape) = match axis {
+            Axis::Row => (self.row, self.col, Row),
+            Axis::Col => (self.col, self.row, Col),
+        };
+        assert_eq!(
+            sizes.iter().sum::<usize>(),
+            len,
+            "Sizes should sum to the length of the axis ({})",
+            len
+        );
+        let m = if self.shape == shape { self.clone() } else { self.change_shape() };
+
+        let mut start = 0usize;
+        sizes
+            .iter()
+            .map(|&k| {
+                let data = m.data[start * other..(start + k) * other].to_vec();
+                start += k;
+                match axis {
+                    Axis::Row => matrix(data, k, other, Row),
+                    Axis::Col => matrix(data, other, k, Col),
+                }
+            })
+            .collect()
+    }
+
+    /// Split matrix into `n` equal pieces along an axis
+    ///
+    /// # Panics
+    /// If `n` is zero or does not divide the length of the axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2 3 4;5 6 7 8");
+    /// let v = a.split_even(Axis::Col, 2);
+    /// assert_eq!(v[0], ml_matrix("1 2;5 6"));
+    /// assert_eq!(v[1], ml_matrix("3 4;7 8"));
+    /// ```
+    pub fn split_even(&self, axis: Axis, n: usize) -> Vec<Matrix> {
+        let len = match axis {
+            Axis::Row => self.row,
+            Axis::Col => self.col,
+        };
+        assert!(n > 0 && len % n == 0, "{} is not divisible into {} pieces", len, n);
+        self.split(axis, &vec![len / n; n])
+    }
+
+    /// Sum along an axis
+    ///
+    /// # Description
+    /// * `Axis::Row` : sum along rows (one value per column)
+    /// * `Axis::Col` : sum along columns (one value per row)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2 3;4 5 6");
+    /// assert_eq!(a.sum_axis(Axis::Row), vec![5f64, 7f64, 9f64]);
+    /// assert_eq!(a.sum_axis(Axis::Col), vec![6f64, 15f64]);
+    /// ```
+    pub fn sum_axis(&self, axis: Axis) -> Vec<f64> {
+        match axis {
+            Axis::Row => (0..self.col).map(|j| self.col(j).iter().sum()).collect(),
+            Axis::Col => (0..self.row).map(|i| self.row(i).iter().sum()).collect(),
+        }
+    }
+
+    /// Sum along an axis with compensated summation
+    ///
+    /// # Description
+    /// Same as `sum_axis`, but each line is summed by the Kahan-Babuska (Neumaier) algorithm,
+    /// so the rounding error does not grow with the length of the line
+    /// (and cancellation between large terms does not swallow small ones).
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1e16 1 -1e16");
+    /// assert_eq!(a.sum_axis(Axis::Col), vec![0f64]);
+    /// assert_eq!(a.sum_axis_accurate(Axis::Col), vec![1f64]);
+    /// ```
+    pub fn sum_axis_accurate(&self, axis: Axis) -> Vec<f64> {
+        match axis {
+            Axis::Row => (0..self.col).map(|j| compensated_sum(&self.col(j))).collect(),
+            Axis::Col => (0..self.row).map(|i| compensated_sum(&self.row(i))).collect(),
+        }
+    }
+
+    /// Largest element-wise absolute difference and its position `(i, j)`
+    ///
+    /// # Description
+    /// Useful to report where two matrices disagree (e.g. in failing tests).
+    /// A `NaN` difference takes precedence over any finite one.
+    /// For empty matrices, `(0, (0, 0))` is returned.
+    ///
+    /// # Panics
+    /// If the dimensions differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let b = ml_matrix("1 2.5;3 3.9");
+    /// let (d, pos) = a.max_abs_diff(&b);
+    /// assert_eq!(d, 0.5);
+    /// assert_eq!(pos, (0, 1));
+    /// ```
+    pub fn max_abs_diff(&self, other: &Matrix) -> (f64, (usize, usize)) {
+        let d = self.diff_matrix(other);
+        let mut result = (0f64, (0usize, 0usize));
+        for i in 0..d.row {
+            for j in 0..d.col {
+                let x = d[(i, j)].abs();
+                if x.is_nan() {
+                    return (x, (i, j));
+                }
+                if x > result.0 {
+                    result = (x, (i, j));
+                }
+            }
+        }
+        result
+    }
+
+    /// Element-wise difference `self - other` (same shape as `self`)
+    ///
+    /// # Panics
+    /// If the dimensions differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let b = ml_matrix("1 1;1 1");
+    /// assert_eq!(a.diff_matrix(&b), ml_matrix("0 1;2 3"));
+    /// ```
+    pub fn diff_matrix(&self, other: &Matrix) -> Matrix {
+        assert!(
+            self.row == other.row && self.col == other.col,
+            "Matrix dimensions should be equal ({}x{} vs {}x{})",
+            self.row,
+            self.col,
+            other.row,
+            other.col
+        );
+        let mut d = self.clone();
+        for i in 0..d.row {
+            for j in 0..d.col {
+                d[(i, j)] -= other[(i, j)];
+            }
+        }
+        d
+    }
+
+    /// Frobenius inner product
+    ///
+    /// # Description
+    /// $\langle A, B \rangle_F = \sum_{i,j} A_{ij} B_{ij} = \text{tr}(A^T B)$
+    ///
+    /// # Panics
+    /// If the dimensions differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let b = ml_matrix("0 1;1 0");
+    /// assert_eq!(a.frobenius_dot(&b), 5f64);
+    /// ```
+    pub fn frobenius_dot(&self, other: &Matrix) -> f64 {
+        assert!(
+            self.row == other.row && self.col == other.col,
+            "Matrix dimensions should be equal ({}x{} vs {}x{})",
+            self.row,
+            self.col,
+            other.row,
+            other.col
+        );
+        if self.shape == other.shape {
+            self.data.iter().zip(other.data.iter()).map(|(a, b)| a * b).sum()
+        } else {
+            let mut s = 0f64;
+            for i in 0..self.row {
+                for j in 0..self.col {
+                    s += self[(i, j)] * other[(i, j)];
+                }
+            }
+            s
+        }
+    }
+
+    /// Angle between matrices with respect to the Frobenius inner product
+    ///
+    /// # Description
+    /// $\theta = \arccos\left(\frac{\langle A, B \rangle_F}{\lVert A \rVert_F \lVert B \rVert_F}\right) \in [0, \pi]$
+    ///
+    /// Returns `NaN` if either matrix is zero.
+    ///
+    /// # Panics
+    /// If the dimensions differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let a = ml_matrix("1 0;0 1");
+    /// let b = ml_matrix("0 1;1 0");
+    /// assert!((a.angle_between(&b) - PI / 2f64).abs() < 1e-15);
+    /// assert!(a.angle_between(&(2f64 * &a)).abs() < 1e-7);
+    /// ```
+    pub fn angle_between(&self, other: &Matrix) -> f64 {
+        let dot = self.frobenius_dot(other);
+        let norm = (self.frobenius_dot(self) * other.frobenius_dot(other)).sqrt();
+        (dot / norm).clamp(-1f64, 1f64).acos()
+    }
+
+    /// Diagonalize square matrix
+    ///
+    /// # Description
+    /// Returns `(P, D)` with `A = P D P^{-1}` where `D` is the diagonal matrix of eigenvalues
+    /// (descending order) and the columns of `P` are the corresponding eigenvectors.
+    ///
+    /// * Symmetric matrices use the Jacobi eigensolver (orthonormal `P`).
+    /// * Other matrices use the shifted QR method on the Hessenberg form for the eigenvalues.
+    ///   Eigenvalues closer than $\sqrt{\epsilon} \lVert A \rVert_F$ are grouped, and the
+    ///   eigenvectors of each group span the null space of $A - \lambda I$ (pivoted QR).
+    /// * The decomposition is verified ($\lVert AP - PD \rVert_F$ & invertibility of `P`),
+    ///   so `None` is returned for non-square or defective matrices,
+    ///   and also for matrices with complex eigenvalues.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("2 1;1 2");
+    /// let (p, d) = a.diagonalize().unwrap();
+    /// assert_eq!(d.diag(), vec![3f64, 1f64]);
+    /// assert_eq!(&p * &d * p.inv(), a);
+    ///
+    /// let b = ml_matrix("1 1;0 2");
+    /// let (p, d) = b.diagonalize().unwrap();
+    /// assert!(eq_vec(&d.diag(), &vec![2f64, 1f64], 1e-12));
+    /// assert!(eq_vec(&(&p * &d * p.inv()).data, &b.data, 1e-12));
+    ///
+    /// assert!(ml_matrix("1 1;0 1").diagonalize().is_none()); // defective
+    /// assert!(ml_matrix("0 -1;1 0").diagonalize().is_none()); // complex eigenvalues
+    /// ```
+    pub fn diagonalize(&self) -> Option<(Matrix, Matrix)> {
+        if self.row != self.col || self.row == 0 {
+            return None;
+        }
+        let n = self.row;
+        let scale = self.norm(Norm::F).max(1f64);
+        let (eigenvalue, p) = if self.is_symmetric() {
+            eigen(self, EigenMethod::Jacobi).extract()
+        } else {
+            let tol = f64::EPSILON.sqrt() * scale;
+            let lambda = real_eigenvalues(self, tol)?;
+            let mut eigenvalue = Vec::with_capacity(n);
+            let mut vectors = Vec::with_capacity(n * n);
+            let mut i = 0;
+            while i < n {
+                let mut j = i + 1;
+                while j < n && lambda[j - 1] - lambda[j] <= tol {
+                    j += 1;
+                }
+                let mu = lambda[i..j].iter().sum::<f64>() / (j - i) as f64;
+                let mut b = self.clone();
+                for k in 0..n {
+                    b[(k, k)] -= mu;
+                }
+                null_space(&b, j - i).into_iter().for_each(|v| vectors.extend(v));
+                eigenvalue.extend(vec![mu; j - i]);
+                i = j;
+            }
+            let mut p = matrix(vectors, n, n, Col);
+            if self.shape == Row {
+                p.change_shape_mut();
+            }
+            (eigenvalue, p)
+        };
+        let mut d = zeros(n, n);
+        d.set_diagonal(&eigenvalue);
+
+        let residual = (self * &p - &p * &d).norm(Norm::F);
+        if !residual.is_finite() || residual > 1e-8 * scale {
+            return None;
+        }
+        let p_det = p.det();
+        if !p_det.is_finite() || p_det.abs() < 1e-10 {
+            return None;
+        }
+        Some((p, d))
+    }
+
+    /// Dominant eigenpair by power iteration
+    ///
+    /// # Description
+    /// Repeats $v \leftarrow Av / \lVert Av \rVert$ from a random starting vector
+    /// (drawn from `global_rng`, see `set_global_seed`) and returns the
+    /// Rayleigh quotient $\lambda = v^T A v$ and the unit eigenvector `v`
+    /// (its largest component is made positive).
+    /// Iteration stops when $\lVert Av - \lambda v \rVert \leq$ `tol` or after `max_iter` steps
+    /// (then the last estimate is returned).
+    ///
+    /// Converges if the largest-magnitude eigenvalue is unique,
+    /// at a rate given by the ratio of the two largest magnitudes.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("2 1;1 2");
+    /// let (lambda, v) = a.power_iteration(1e-10, 1000);
+    /// assert!((lambda - 3f64).abs() < 1e-10);
+    /// let s = 0.5f64.sqrt();
+    /// assert!(eq_vec(&v, &vec![s, s], 1e-10));
+    /// ```
+    pub fn power_iteration(&self, tol: f64, max_iter: usize) -> (f64, Vec<f64>) {
+        assert_eq!(self.row, self.col, "Power iteration requires a square matrix");
+        self.rayleigh_iteration(|v| self * v, tol, max_iter)
+    }
+
+    /// Eigenpair with the eigenvalue nearest to `shift` by inverse power iteration
+    ///
+    /// # Description
+    /// Power iteration with $(A - \sigma I)^{-1}$ (`shift` $= \sigma$),
+    /// whose dominant eigenvalue corresponds to the eigenvalue of `A` closest to $\sigma$.
+    /// $A - \sigma I$ is LU-factored once and each step solves with the factors.
+    /// The returned eigenvalue is the Rayleigh quotient with `A` itself,
+    /// and the stopping rule is the same as `power_iteration`.
+    /// `shift` should not be exactly an eigenvalue (singular shifted matrix).
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("4 1 0;1 3 1;0 1 2");
+    /// let (lambda, v) = a.inverse_power_iteration(2.5, 1e-10, 1000);
+    /// assert!((lambda - 3f64).abs() < 1e-10);
+    /// assert!(eq_vec(&(&a * &v), &v.mul_scalar(lambda), 1e-9));
+    /// ```
+    pub fn inverse_power_iteration(&self, shift: f64, tol: f64, max_iter: usize) -> (f64, Vec<f64>) {
+        assert_eq!(self.row, self.col, "Inverse power iteration requires a square matrix");
+        let mut shifted = self.clone();
+        for i in 0..self.row {
+            shifted[(i, i)] -= shift;
+        }
+        let lu = shifted.lu();
+        self.rayleigh_iteration(|v| lu.solve(v), tol, max_iter)
+    }
+
+    /// Power iteration with operator `op`, Rayleigh quotient & residual with `self`
+    fn rayleigh_iteration<F: Fn(&Vec<f64>) -> Vec<f64>>(
+        &self,
+        op: F,
+        tol: f64,
+        max_iter: usize,
+    ) -> (f64, Vec<f64>) {
+        let n = self.row;
+        let mut v = random_unit_vector(n);
+        let mut lambda = 0f64;
+        for _ in 0..max_iter {
+            let av = self * &v;
+            lambda = v.dot(&av);
+            let residual = av.sub_vec(&v.mul_scalar(lambda)).norm(Norm::L2);
+            if residual <= tol {
+                break;
+            }
+            v = op(&v).normalize(Norm::L2);
+        }
+        let max_idx = (0..n).fold(0, |k, i| if v[i].abs() > v[k].abs() { i } else { k });
+        if n > 0 && v[max_idx] < 0f64 {
+            v = v.mul_scalar(-1f64);
+        }
+        (lambda, v)
+    }
+
+    /// Check whether the matrix is square with zero off-diagonal entries
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// assert!(ml_matrix("2 0;0 3").is_diagonal());
+    /// assert!(!ml_matrix("2 1;0 3").is_diagonal());
+    /// assert!(!ml_matrix("2 0 0;0 3 0").is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        if self.row != self.col {
+            return false;
+        }
+        (0..self.row).all(|i| (0..self.col).all(|j| i == j || self[(i, j)] == 0f64))
+    }
+
+    /// Solve diagonal system `D x = b` by element-wise division
+    ///
+    /// # Description
+    /// Fast path used by `solve` & `solve_mat` for diagonal matrices.
+    /// Returns `DiagSolveError` if the matrix is not diagonal,
+    /// the length of `b` differs, or a diagonal entry is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let d = ml_matrix("2 0;0 4");
+    /// assert_eq!(d.diag_solve(&[1f64, 2f64]).unwrap(), vec![0.5, 0.5]);
+    /// assert!(ml_matrix("2 0;0 0").diag_solve(&[1f64, 2f64]).is_err());
+    /// ```
+    pub fn diag_solve(&self, b: &[f64]) -> anyhow::Result<Vec<f64>> {
+        if !self.is_diagonal() {
+            bail!(DiagSolveError::NotDiagonal);
+        }
+        if b.len() != self.row {
+            bail!(DiagSolveError::LengthMismatch(self.row, b.len()));
+        }
+        let mut x = Vec::with_capacity(b.len());
+        for (i, &bi) in b.iter().enumerate() {
+            let d = self[(i, i)];
+            if d == 0f64 {
+                bail!(DiagSolveError::ZeroDiagonal(i));
+            }
+            x.push(bi / d);
+        }
+        Ok(x)
+    }
+
+    /// Sign & natural log of the absolute determinant
+    ///
+    /// # Description
+    /// Computed from the LU factor by summing logs of the pivots,
+    /// so it stays finite where `det` overflows or underflows.
+    /// Returns `(sign, ln|det|)` with `det = sign * exp(ln|det|)`.
+    /// Singular matrix gives `(0, -inf)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let (sgn, logdet) = a.slogdet();
+    /// assert_eq!(sgn, -1f64);
+    /// assert!((logdet - 2f64.ln()).abs() < 1e-12);
+    ///
+    /// // det overflows to inf, but log-determinant is finite
+    /// let d = eye(200) * 1e10;
+    /// assert!(d.det().is_infinite());
+    /// assert!((d.slogdet().1 - 2000f64 * 10f64.ln()).abs() < 1e-9);
+    /// ```
+    pub fn slogdet(&self) -> (f64, f64) {
+        assert_eq!(self.row, self.col);
+        self.lu().slogdet()
+    }
+
+    /// Minimum-norm solution of `A x = b` via complete orthogonal decomposition
+    ///
+    /// # Description
+    /// * Householder QR with column pivoting `A P = Q R` reveals the numerical rank `r`
+    ///   (diagonal entries of `R` below `max(m, n) * eps * |R_00|` are treated as zero).
+    /// * The leading `r` rows `[R_11 R_12]` are orthogonally reduced to triangular form,
+    ///   which gives the solution with the smallest 2-norm.
+    /// * Works for singular or rectangular `A`. If the system is inconsistent,
+    ///   the minimum-norm least-squares solution is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// // Singular but consistent: x + 2y = 1
+    /// let a = ml_matrix("1 2;2 4");
+    /// let x = a.solve_min_norm(&[1f64, 2f64]);
+    /// assert!(eq_vec(&x, &vec![0.2, 0.4], 1e-12));
+    /// ```
+    pub fn solve_min_norm(&self, b: &[f64]) -> Vec<f64> {
+        assert_eq!(b.len(), self.row, "Length of b should be equal to the number of rows");
+        let (m, n) = (self.row, self.col);
+        let mut cols: Vec<Vec<f64>> = (0..n).map(|j| self.col(j)).collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut c = b.to_vec();
+
+        // Householder QR with column pivoting
+        let mut diag = vec![];
+        for k in 0..min(m, n) {
+            let (p, _) = (k..n)
+                .map(|j| (j, cols[j][k..].iter().map(|x| x * x).sum::<f64>()))
+                .fold((k, -1f64), |acc, x| if x.1 > acc.1 { x } else { acc });
+            cols.swap(k, p);
+            perm.swap(k, p);
+            let (v, alpha) = householder_vector(&cols[k][k..]);
+            for col in cols.iter_mut().skip(k + 1) {
+                householder_apply(&v, &mut col[k..]);
+            }
+            householder_apply(&v, &mut c[k..]);
+            cols[k][k] = alpha;
+            cols[k][k + 1..].iter_mut().for_each(|x| *x = 0f64);
+            diag.push(alpha);
+        }
+        let tol = max(m, n) as f64 * f64::EPSILON * diag.first().map_or(0f64, |d| d.abs());
+        let r = diag.iter().take_while(|d| d.abs() > tol).count();
+        if r == 0 {
+            return vec![0f64; n];
+        }
+
+        // QR of [R_11 R_12]^T (n x r) : [R_11 R_12] = L Z^T with L = R_2^T
+        let mut w: Vec<Vec<f64>> = (0..r)
+            .map(|i| (0..n).map(|j| if j >= i { cols[j][i] } else { 0f64 }).collect())
+            .collect();
+        let mut reflectors = Vec::with_capacity(r);
+        for k in 0..r {
+            let (v, alpha) = householder_vector(&w[k][k..]);
+            for col in w.iter_mut().skip(k + 1) {
+                householder_apply(&v, &mut col[k..]);
+            }
+            w[k][k] = alpha;
+            reflectors.push(v);
+        }
+
+        // Forward substitution with R_2^T (w[i][k] = R_2[k][i])
+        let mut z = vec![0f64; n];
+        for i in 0..r {
+            let s: f64 = (0..i).map(|k| w[i][k] * z[k]).sum();
+            z[i] = (c[i] - s) / w[i][i];
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            householder_apply(v, &mut z[k..]);
+        }
+
+        let mut x = vec![0f64; n];
+        for (j, &pj) in perm.iter().enumerate() {
+            x[pj] = z[j];
+        }
+        x
+    }
+
+    /// Integer power of square matrix
+    ///
+    /// # Description
+    /// Exponentiation by squaring. `pow(0)` is the identity.
+    /// The result has the same shape as `self`.
+    ///
+    /// # Panics
+    /// If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 1;1 0");
+    /// assert_eq!(a.pow(10), ml_matrix("89 55;55 34"));
+    /// assert_eq!(a.pow(0), eye(2));
+    /// ```
+    pub fn pow(&self, n: usize) -> Matrix {
+        assert_eq!(
+            self.row, self.col,
+            "Matrix power requires a square matrix ({}x{})",
+            self.row, self.col
+        );
+        let mut result = eye(self.row);
+        if self.shape == Col {
+            result = result.change_shape();
+        }
+        if n == 0 {
+            return result;
+        }
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 1 {
+            if n % 2 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            n /= 2;
+        }
+        let mut result = &result * &base;
+        if result.shape != self.shape {
+            result = result.change_shape();
+        }
+        result
+    }
+
+    /// Accumulate outer product $u v^T$ in place
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = zeros(2, 3);
+    /// a.add_outer(&[1f64, 2f64], &[1f64, 0f64, -1f64]);
+    /// a.add_outer(&[1f64, 1f64], &[0f64, 1f64, 0f64]);
+    /// assert_eq!(a, ml_matrix("1 1 -1;2 1 -2"));
+    /// ```
+    pub fn add_outer(&mut self, u: &[f64], v: &[f64]) {
+        assert_eq!(u.len(), self.row, "Length of u should be equal to the number of rows");
+        assert_eq!(v.len(), self.col, "Length of v should be equal to the number of columns");
+        for i in 0..self.row {
+            for j in 0..self.col {
+                self[(i, j)] += u[i] * v[j];
+            }
+        }
+    }
+
+    /// Substitute main diagonal with a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = zeros(2, 3);
+    /// a.set_diagonal(&[1f64, 2f64]);
+    /// assert_eq!(a, ml_matrix("1 0 0;0 2 0"));
+    /// ```
+    pub fn set_diagonal(&mut self, v: &[f64]) {
+        let n = min(self.row, self.col);
+        assert_eq!(v.len(), n, "Length of vector should be equal to the length of diagonal");
+        for i in 0..n {
+            self[(i, i)] = v[i];
+        }
+    }
+
+    /// Mutable iterator over elements with their positions `(i, j)`
+    ///
+    /// # Description
+    /// Elements are visited in storage order (row-major for `Row`, column-major for `Col`).
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = zeros(2, 3);
+    /// for ((i, j), x) in a.iter_mut_indexed() {
+    ///     *x = (10 * i + j) as f64;
+    /// }
+    /// assert_eq!(a, ml_matrix("0 1 2;10 11 12"));
+    /// ```
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = ((usize, usize), &mut f64)> {
+        let (row, col, shape) = (self.row, self.col, self.shape);
+        self.data.iter_mut().enumerate().map(move |(k, x)| {
+            let pos = match shape {
+                Shape::Row => (k / col, k % col),
+                Shape::Col => (k % row, k / row),
+            };
+            (pos, x)
+        })
+    }
+
+    /// Flatten matrix to vector in row-major (`Row`) or column-major (`Col`) order
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2 3;4 5 6");
+    /// assert_eq!(a.flatten(Row), vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64]);
+    /// assert_eq!(a.flatten(Col), vec![1f64, 4f64, 2f64, 5f64, 3f64, 6f64]);
+    /// ```
+    pub fn flatten(&self, shape: Shape) -> Vec<f64> {
+        if self.shape == shape {
+            self.data.clone()
+        } else {
+            self.change_shape().data
+        }
+    }
+
+    /// Reshape matrix with elements in a given order
+    ///
+    /// # Description
+    /// Elements are read in `shape` order (see `flatten`) and
+    /// filled into a new `r x c` matrix in the same order.
+    /// For a raw reinterpretation of the data, use `Scalable::reshape`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2 3;4 5 6");
+    /// let b = a.reshape_ordered(3, 2, Row);
+    /// assert_eq!(b, ml_matrix("1 2;3 4;5 6"));
+    /// ```
+    pub fn reshape_ordered(&self, r: usize, c: usize, shape: Shape) -> Matrix {
+        assert_eq!(
+            r * c,
+            self.row * self.col,
+            "Can't reshape {}x{} matrix ({} elements) to {}x{} ({} elements)",
+            self.row,
+            self.col,
+            self.row * self.col,
+            r,
+            c,
+            r * c
+        );
+        matrix(self.flatten(shape), r, c, shape)
+    }
+
+    /// Multiply all elements by a factor in place
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = eye(2);
+    /// a.scale_mut(2f64);
+    /// assert_eq!(a, ml_matrix("2 0;0 2"));
+    /// ```
+    pub fn scale_mut(&mut self, factor: f64) {
+        self.data.iter_mut().for_each(|x| *x *= factor);
+    }
+
+    /// Add a value to all elements in place
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let mut a = eye(2);
+    /// a.add_scalar_mut(1f64);
+    /// assert_eq!(a, ml_matrix("2 1;1 2"));
+    /// ```
+    pub fn add_scalar_mut(&mut self, value: f64) {
+        self.data.iter_mut().for_each(|x| *x += value);
+    }
+
+    /// Multiply all elements by a factor
+    pub fn scale(&self, factor: f64) -> Matrix {
+        let mut m = self.clone();
+        m.scale_mut(factor);
+        m
+    }
+
+    /// Add a value to all elements
+    pub fn add_scalar(&self, value: f64) -> Matrix {
+        let mut m = self.clone();
+        m.add_scalar_mut(value);
+        m
+    }
+
+    /// Maximum element
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 5;3 2");
+    /// assert_eq!(a.max(), 5f64);
+    /// ```
+    pub fn max(&self) -> f64 {
+        let (i, j) = self.argmax();
+        self[(i, j)]
+    }
+
+    /// Minimum element
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 5;3 -2");
+    /// assert_eq!(a.min(), -2f64);
+    /// ```
+    pub fn min(&self) -> f64 {
+        let (i, j) = self.argmin();
+        self[(i, j)]
+    }
 
-        for i in 0..row {
-            for j in 0..col {
-                mat[(i, j)] = f(i, j).into();
-            }
-        }
-        mat
+    /// Index `(row, col)` of the first maximum (row-major scan)
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 5;5 2");
+    /// assert_eq!(a.argmax(), (0, 1));
+    /// ```
+    pub fn argmax(&self) -> (usize, usize) {
+        self.arg_extreme(|x, y| x > y)
     }
 
-    /// Matrix to `Vec<Vec<f64>>`
+    /// Index `(row, col)` of the first minimum (row-major scan)
     ///
-    /// To send `Matrix` to `inline-python`
-    pub fn to_vec(&self) -> Vec<Vec<f64>> {
-        let mut result = vec![vec![0f64; self.col]; self.row];
-        for i in 0..self.row {
-            result[i] = self.row(i);
-        }
-        result
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 5;0 0");
+    /// assert_eq!(a.argmin(), (1, 0));
+    /// ```
+    pub fn argmin(&self) -> (usize, usize) {
+        self.arg_extreme(|x, y| x < y)
     }
 
-    pub fn to_diag(&self) -> Matrix {
-        assert_eq!(self.row, self.col, "Should be square matrix");
-        let mut result = matrix(vec![0f64; self.row * self.col], self.row, self.col, Row);
-        let diag = self.diag();
+    fn arg_extreme<F: Fn(f64, f64) -> bool>(&self, better: F) -> (usize, usize) {
+        assert!(
+            self.row > 0 && self.col > 0,
+            "Can't find extremum of an empty matrix ({}x{})",
+            self.row,
+            self.col
+        );
+        let mut idx = (0usize, 0usize);
         for i in 0..self.row {
-            result[(i, i)] = diag[i];
+            for j in 0..self.col {
+                if better(self[(i, j)], self[idx]) {
+                    idx = (i, j);
+                }
+            }
         }
-        result
+        idx
     }
 
     /// Submatrix
@@ -1453,10 +2619,195 @@ impl Matrix {
     ///     assert_eq!(m, matrix(c!(1,2,3,4), 2, 2, Row));
     /// }
     /// ```
+    #[cfg(feature = "std")]
     pub fn from_series(series: &Series, row: usize, col: usize, shape: Shape) -> Self {
         let v: Vec<f64> = series.to_vec();
         matrix(v, row, col, shape)
     }
+
+    /// Serialize matrix to compact binary format
+    ///
+    /// # Description
+    /// Layout (little-endian):
+    ///
+    /// | Field   | Size            |
+    /// | :-----: | :-------------: |
+    /// | magic   | 4 (`b"PRXM"`)   |
+    /// | version | 1               |
+    /// | rows    | 8 (`u64`)       |
+    /// | cols    | 8 (`u64`)       |
+    /// | shape   | 1 (Row=0, Col=1)|
+    /// | data    | 8 * rows * cols |
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = ml_matrix("1 2;3 4");
+    /// let bytes = a.to_bytes();
+    /// let b = Matrix::from_bytes(&bytes).unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MATRIX_BYTES_HEADER + 8 * self.data.len());
+        bytes.extend_from_slice(MATRIX_BYTES_MAGIC);
+        bytes.push(MATRIX_BYTES_VERSION);
+        bytes.extend_from_slice(&(self.row as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.col as u64).to_le_bytes());
+        bytes.push(match self.shape {
+            Row => 0u8,
+            Col => 1u8,
+        });
+        for x in self.data.iter() {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserialize matrix from binary format of `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Matrix> {
+        let (row, col, shape) = parse_matrix_bytes_header(bytes)?;
+        let data = bytes[MATRIX_BYTES_HEADER..]
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect::<Vec<f64>>();
+        Ok(matrix(data, row, col, shape))
+    }
+}
+
+/// Error for binary (de)serialization of `Matrix`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MatrixBytesError {
+    TooShort,
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    InvalidShape(u8),
+    LengthMismatch(usize, usize), // expected, found
+}
+
+impl fmt::Display for MatrixBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixBytesError::TooShort => write!(f, "Buffer is too short to contain a matrix header"),
+            MatrixBytesError::InvalidMagic => write!(f, "Invalid magic number for matrix binary format"),
+            MatrixBytesError::UnsupportedVersion(v) => write!(f, "Unsupported matrix binary format version: {}", v),
+            MatrixBytesError::InvalidShape(s) => write!(f, "Invalid shape tag: {}", s),
+            MatrixBytesError::LengthMismatch(e, l) => write!(f, "Expected {} bytes of data, but found {}", e, l),
+        }
+    }
+}
+
+impl Error for MatrixBytesError {}
+
+/// Error for `Matrix::diag_solve`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiagSolveError {
+    NotDiagonal,
+    LengthMismatch(usize, usize), // expected, found
+    ZeroDiagonal(usize),
+}
+
+impl fmt::Display for DiagSolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagSolveError::NotDiagonal => write!(f, "Matrix is not diagonal"),
+            DiagSolveError::LengthMismatch(e, l) => write!(f, "Expected vector of length {}, but found {}", e, l),
+            DiagSolveError::ZeroDiagonal(i) => write!(f, "Try solve for Singular matrix (zero diagonal at {})", i),
+        }
+    }
+}
+
+impl Error for DiagSolveError {}
+
+pub(crate) const MATRIX_BYTES_MAGIC: &[u8; 4] = b"PRXM";
+pub(crate) const MATRIX_BYTES_VERSION: u8 = 1;
+pub(crate) const MATRIX_BYTES_HEADER: usize = 22;
+
+/// Validate header of matrix binary format & return `(row, col, shape)`
+pub(crate) fn parse_matrix_bytes_header(bytes: &[u8]) -> anyhow::Result<(usize, usize, Shape)> {
+    if bytes.len() < MATRIX_BYTES_HEADER {
+        bail!(MatrixBytesError::TooShort);
+    }
+    if &bytes[0..4] != MATRIX_BYTES_MAGIC {
+        bail!(MatrixBytesError::InvalidMagic);
+    }
+    if bytes[4] != MATRIX_BYTES_VERSION {
+        bail!(MatrixBytesError::UnsupportedVersion(bytes[4]));
+    }
+    let row = u64::from_le_bytes(bytes[5..13].try_into().unwrap()) as usize;
+    let col = u64::from_le_bytes(bytes[13..21].try_into().unwrap()) as usize;
+    let shape = match bytes[21] {
+        0 => Row,
+        1 => Col,
+        s => bail!(MatrixBytesError::InvalidShape(s)),
+    };
+    let expected = row
+        .checked_mul(col)
+        .and_then(|n| n.checked_mul(8))
+        .unwrap_or(usize::MAX);
+    let found = bytes.len() - MATRIX_BYTES_HEADER;
+    if expected != found {
+        bail!(MatrixBytesError::LengthMismatch(expected, found));
+    }
+    Ok((row, col, shape))
+}
+
+/// JSON format for `Matrix` (`row`, `col`, `shape` & `data`)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let a = ml_matrix("1 2;3 4").change_shape();
+/// let s = a.to_json();
+/// let b = Matrix::from_json(&s).unwrap();
+/// assert_eq!(b.shape, Col);
+/// assert_eq!(a.data, b.data);
+/// # }
+/// ```
+/// JSON layout of `Matrix` with non-finite values as strings
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct MatrixJSON {
+    #[serde(with = "crate::structure::dataframe::non_finite_serde")]
+    data: Vec<f64>,
+    row: usize,
+    col: usize,
+    shape: Shape,
+}
+
+#[cfg(feature = "serde")]
+impl WithJSON for Matrix {
+    fn to_json(&self) -> String {
+        let m = MatrixJSON {
+            data: self.data.clone(),
+            row: self.row,
+            col: self.col,
+            shape: self.shape,
+        };
+        serde_json::to_string(&m).expect("Can't serialize Matrix")
+    }
+
+    fn from_json(s: &str) -> Result<Self, Box<dyn Error>> {
+        let m: MatrixJSON = serde_json::from_str(s)?;
+        let m = Matrix {
+            data: m.data,
+            row: m.row,
+            col: m.col,
+            shape: m.shape,
+        };
+        if m.data.len() != m.row * m.col {
+            return Err(format!(
+                "Length of data ({}) is different from row * col ({})",
+                m.data.len(),
+                m.row * m.col
+            )
+            .into());
+        }
+        Ok(m)
+    }
 }
 
 // =============================================================================
@@ -2504,10 +3855,10 @@ impl<'a> Div<usize> for &'a Matrix {
 /// let a = matrix(vec![1,2,3,4],2,2,Row);
 /// assert_eq!(a[(0,1)], 2f64);
 /// ```
-impl Index<(usize, usize)> for Matrix {
-    type Output = f64;
+impl<T: MatrixScalar> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
 
-    fn index(&self, pair: (usize, usize)) -> &f64 {
+    fn index(&self, pair: (usize, usize)) -> &T {
         let p = self.ptr();
         let i = pair.0;
         let j = pair.1;
@@ -2535,8 +3886,8 @@ impl Index<(usize, usize)> for Matrix {
 ///     assert_eq!(a, matrix(c!(1,2,3,10), 2, 2, Row));
 /// }
 /// ```
-impl IndexMut<(usize, usize)> for Matrix {
-    fn index_mut(&mut self, pair: (usize, usize)) -> &mut f64 {
+impl<T: MatrixScalar> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, pair: (usize, usize)) -> &mut T {
         let i = pair.0;
         let j = pair.1;
         let r = self.row;
@@ -2556,6 +3907,126 @@ impl IndexMut<(usize, usize)> for Matrix {
     }
 }
 
+// =============================================================================
+// Arithmetic for generic Matrix
+// =============================================================================
+impl<T: MatrixScalar> Matrix<T> {
+    /// Element-wise binary operation (shape of `self` is preserved)
+    fn elementwise<F: Fn(T, T) -> T>(&self, other: &Matrix<T>, f: F) -> Matrix<T> {
+        assert_eq!(self.row, other.row, "Matrices should have same number of rows");
+        assert_eq!(self.col, other.col, "Matrices should have same number of columns");
+        let data = if self.shape == other.shape {
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect()
+        } else {
+            let other = other.change_shape();
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect()
+        };
+        Matrix::from_vec(data, self.row, self.col, self.shape)
+    }
+
+    /// Naive matrix multiplication
+    fn naive_mul(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.col, other.row, "Dimension mismatch for matrix multiplication");
+        let mut result = Matrix::from_vec(vec![T::zero(); self.row * other.col], self.row, other.col, Row);
+        for i in 0..self.row {
+            for j in 0..other.col {
+                let mut s = T::zero();
+                for k in 0..self.col {
+                    s = s + self[(i, k)] * other[(k, j)];
+                }
+                result[(i, j)] = s;
+            }
+        }
+        result
+    }
+}
+
+macro_rules! impl_generic_matrix_ops {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq for Matrix<$t> {
+                fn eq(&self, other: &Matrix<$t>) -> bool {
+                    if self.row != other.row || self.col != other.col {
+                        return false;
+                    }
+                    if self.shape == other.shape {
+                        self.data == other.data
+                    } else {
+                        self.data == other.change_shape().data
+                    }
+                }
+            }
+
+            impl Add<Matrix<$t>> for Matrix<$t> {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    self.elementwise(&other, |x, y| x + y)
+                }
+            }
+
+            impl<'a, 'b> Add<&'b Matrix<$t>> for &'a Matrix<$t> {
+                type Output = Matrix<$t>;
+
+                fn add(self, other: &'b Matrix<$t>) -> Self::Output {
+                    self.elementwise(other, |x, y| x + y)
+                }
+            }
+
+            impl Sub<Matrix<$t>> for Matrix<$t> {
+                type Output = Self;
+
+                fn sub(self, other: Self) -> Self {
+                    self.elementwise(&other, |x, y| x - y)
+                }
+            }
+
+            impl<'a, 'b> Sub<&'b Matrix<$t>> for &'a Matrix<$t> {
+                type Output = Matrix<$t>;
+
+                fn sub(self, other: &'b Matrix<$t>) -> Self::Output {
+                    self.elementwise(other, |x, y| x - y)
+                }
+            }
+
+            impl Mul<$t> for Matrix<$t> {
+                type Output = Self;
+
+                fn mul(self, other: $t) -> Self {
+                    let data = self.data.iter().map(|&x| x * other).collect();
+                    Matrix::from_vec(data, self.row, self.col, self.shape)
+                }
+            }
+
+            impl Mul<Matrix<$t>> for Matrix<$t> {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    self.naive_mul(&other)
+                }
+            }
+
+            impl<'a, 'b> Mul<&'b Matrix<$t>> for &'a Matrix<$t> {
+                type Output = Matrix<$t>;
+
+                fn mul(self, other: &'b Matrix<$t>) -> Self::Output {
+                    self.naive_mul(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_generic_matrix_ops!(f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, Interval);
+
 // =============================================================================
 // Functional Programming Tools (Hand-written)
 // =============================================================================
@@ -2792,6 +4263,75 @@ pub trait LinearAlgebra {
     fn is_symmetric(&self) -> bool;
 }
 
+/// Kahan-Babuska (Neumaier) compensated summation
+fn compensated_sum(v: &[f64]) -> f64 {
+    let mut s = 0f64;
+    let mut c = 0f64;
+    for &x in v {
+        let t = s + x;
+        if s.abs() >= x.abs() {
+            c += (s - t) + x;
+        } else {
+            c += (x - t) + s;
+        }
+        s = t;
+    }
+    s + c
+}
+
+/// Householder vector `v` and `alpha` such that `(I - 2vv^T/v^Tv) x = alpha e_1`
+fn householder_vector(x: &[f64]) -> (Vec<f64>, f64) {
+    let norm = x.iter().map(|t| t * t).sum::<f64>().sqrt();
+    let mut v = x.to_vec();
+    if norm == 0f64 {
+        return (v, 0f64);
+    }
+    let alpha = if x[0] >= 0f64 { -norm } else { norm };
+    v[0] -= alpha;
+    (v, alpha)
+}
+
+/// Apply Householder reflection `I - 2vv^T/v^Tv` to `y` in place
+fn householder_apply(v: &[f64], y: &mut [f64]) {
+    let vv: f64 = v.iter().map(|t| t * t).sum();
+    if vv == 0f64 {
+        return;
+    }
+    let t = 2f64 * v.iter().zip(y.iter()).map(|(a, b)| a * b).sum::<f64>() / vv;
+    y.iter_mut().zip(v).for_each(|(yi, vi)| *yi -= t * vi);
+}
+
+/// Orthonormal basis of the `k`-dimensional (numerical) null space of square `a`
+///
+/// Householder QR with column pivoting of `a^T` moves the row space of `a`
+/// to the leading columns of `Q`, so the last `k` columns of `Q` are returned.
+fn null_space(a: &Matrix, k: usize) -> Vec<Vec<f64>> {
+    let n = a.row;
+    let mut cols: Vec<Vec<f64>> = (0..n).map(|i| a.row(i)).collect();
+    let mut reflectors = Vec::with_capacity(n);
+    for j in 0..n {
+        let (p, _) = (j..n)
+            .map(|i| (i, cols[i][j..].iter().map(|x| x * x).sum::<f64>()))
+            .fold((j, -1f64), |acc, x| if x.1 > acc.1 { x } else { acc });
+        cols.swap(j, p);
+        let (v, _) = householder_vector(&cols[j][j..]);
+        for col in cols.iter_mut().skip(j + 1) {
+            householder_apply(&v, &mut col[j..]);
+        }
+        reflectors.push(v);
+    }
+    (n - k..n)
+        .map(|i| {
+            let mut e = vec![0f64; n];
+            e[i] = 1f64;
+            for (j, v) in reflectors.iter().enumerate().rev() {
+                householder_apply(v, &mut e[j..]);
+            }
+            e
+        })
+        .collect()
+}
+
 pub fn diag(n: usize) -> Matrix {
     let mut v: Vec<f64> = vec![0f64; n * n];
     for i in 0..n {
@@ -2835,21 +4375,39 @@ impl PQLU {
     }
 
     pub fn det(&self) -> f64 {
-        // sgn of perms
-        let mut sgn_p = 1f64;
-        let mut sgn_q = 1f64;
-        for (i, &j) in self.p.iter().enumerate() {
-            if i != j {
-                sgn_p *= -1f64;
+        self.u.diag().reduce(1f64, |x, y| x * y) * self.perm_sign()
+    }
+
+    /// Sign & natural log of the absolute determinant
+    ///
+    /// # Description
+    /// Sums the logs of the pivots of `U` instead of multiplying them,
+    /// so it neither overflows nor underflows.
+    /// Singular matrix gives `(0, -inf)`.
+    pub fn slogdet(&self) -> (f64, f64) {
+        let mut sgn = self.perm_sign();
+        let mut logdet = 0f64;
+        for d in self.u.diag() {
+            if d == 0f64 {
+                return (0f64, f64::NEG_INFINITY);
             }
+            sgn *= d.signum();
+            logdet += d.abs().ln();
         }
-        for (i, &j) in self.q.iter().enumerate() {
-            if i != j {
-                sgn_q *= -1f64;
+        (sgn, logdet)
+    }
+
+    /// Sign of the permutations `P` & `Q`
+    fn perm_sign(&self) -> f64 {
+        let mut sgn = 1f64;
+        for perm in [&self.p, &self.q].iter() {
+            for (i, &j) in perm.iter().enumerate() {
+                if i != j {
+                    sgn *= -1f64;
+                }
             }
         }
-
-        self.u.diag().reduce(1f64, |x, y| x * y) * sgn_p * sgn_q
+        sgn
     }
 
     pub fn inv(&self) -> Matrix {
@@ -2869,6 +4427,28 @@ impl PQLU {
         }
         m
     }
+
+    /// Solve `A x = b` with the factorization of `A`
+    ///
+    /// # Description
+    /// Reuses the factorization, e.g. for several right-hand sides.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let lu = ml_matrix("1 2;3 4").lu();
+    /// assert!(eq_vec(&lu.solve(&vec![5f64, 11f64]), &vec![1f64, 2f64], 1e-12));
+    /// assert!(eq_vec(&lu.solve(&vec![1f64, 3f64]), &vec![1f64, 0f64], 1e-12));
+    /// ```
+    pub fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let mut v = b.to_vec();
+        v.swap_with_perm(&self.p.iter().cloned().enumerate().collect());
+        let z = self.l.forward_subs(&v);
+        let mut y = self.u.back_subs(&z);
+        y.swap_with_perm(&self.q.iter().cloned().enumerate().rev().collect());
+        y
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -3059,7 +4639,7 @@ impl LinearAlgebra for Matrix {
                 let l_i = l.col_mut(i);
                 for j in i + 1..l.col - 1 {
                     let dst = p[j];
-                    std::ptr::swap(l_i[j], l_i[dst]);
+                    core::ptr::swap(l_i[j], l_i[dst]);
                 }
             }
         }
@@ -3530,7 +5110,15 @@ impl LinearAlgebra for Matrix {
     ///
     /// * Biswa Nath Datta, *Numerical Linear Algebra and Applications, Second Edition*
     /// * Ke Chen, *Matrix Preconditioning Techniques and Applications*, Cambridge Monographs on Applied and Computational Mathematics
+    ///
+    /// Diagonal matrices are solved directly by `diag_solve` regardless of `sk`.
     fn solve(&self, b: &Vec<f64>, sk: SolveKind) -> Vec<f64> {
+        if self.is_diagonal() {
+            return match self.diag_solve(b) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            };
+        }
         match sk {
             #[cfg(feature = "O3")]
             SolveKind::LU => {
@@ -3546,16 +5134,7 @@ impl LinearAlgebra for Matrix {
                 }
             }
             #[cfg(not(feature = "O3"))]
-            SolveKind::LU => {
-                let lu = self.lu();
-                let (p, q, l, u) = lu.extract();
-                let mut v = b.clone();
-                v.swap_with_perm(&p.into_iter().enumerate().collect());
-                let z = l.forward_subs(&v);
-                let mut y = u.back_subs(&z);
-                y.swap_with_perm(&q.into_iter().enumerate().rev().collect());
-                y
-            }
+            SolveKind::LU => self.lu().solve(b),
             SolveKind::WAZ => {
                 let wazd = match self.waz(Form::Identity) {
                     None => panic!("Can't solve by WAZ with Singular matrix!"),
@@ -3569,6 +5148,17 @@ impl LinearAlgebra for Matrix {
     }
 
     fn solve_mat(&self, m: &Matrix, sk: SolveKind) -> Matrix {
+        if self.is_diagonal() {
+            let mut x = m.clone();
+            for j in 0..m.col {
+                let y = match self.diag_solve(&m.col(j)) {
+                    Ok(y) => y,
+                    Err(e) => panic!("{}", e),
+                };
+                x.subs_col(j, &y);
+            }
+            return x;
+        }
         match sk {
             #[cfg(feature = "O3")]
             SolveKind::LU => {
@@ -3723,6 +5313,7 @@ impl Div<Matrix> for Matrix {
 }
 
 
+#[cfg(feature = "std")]
 impl ExpLogOps for Matrix {
     type Float = f64;
     fn exp(&self) -> Self {
@@ -3742,6 +5333,22 @@ impl ExpLogOps for Matrix {
     }
 }
 
+#[cfg(feature = "std")]
+impl StableExpLogOps for Matrix {
+    fn log1p(&self) -> Self {
+        self.fmap(|x| x.ln_1p())
+    }
+
+    fn expm1(&self) -> Self {
+        self.fmap(|x| x.exp_m1())
+    }
+
+    fn softplus(&self) -> Self {
+        self.fmap(softplus)
+    }
+}
+
+#[cfg(feature = "std")]
 impl PowOps for Matrix {
     type Float = f64;
 
@@ -3762,6 +5369,7 @@ impl PowOps for Matrix {
     }
 }
 
+#[cfg(feature = "std")]
 impl TrigOps for Matrix {
     fn sin_cos(&self) -> (Self, Self) {
         let (sin, cos) = self.data.iter().map(|x| x.sin_cos()).unzip();
@@ -3820,6 +5428,7 @@ impl TrigOps for Matrix {
     }
 }
 
+#[cfg(feature = "std")]
 impl Numeric<f64> for Matrix {}
 
 // =============================================================================
@@ -4121,6 +5730,128 @@ pub fn gevm(alpha: f64, a: &Vec<f64>, b: &Matrix, beta: f64, c: &mut Vec<f64>) {
     }
 }
 
+/// Gram matrix ($X^T X$)
+///
+/// # Description
+/// The transpose is expressed by strides, so `data` is never copied.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let x = ml_matrix("1 2;3 4;5 6");
+/// assert_eq!(gram(&x), ml_matrix("35 44;44 56"));
+/// ```
+pub fn gram(data: &Matrix) -> Matrix {
+    if data.row == 0 || data.col == 0 {
+        return zeros(data.col, data.col);
+    }
+    let m = data.col;
+    let k = data.row;
+    let (rs, cs) = match data.shape {
+        Row => (data.col as isize, 1isize),
+        Col => (1isize, data.row as isize),
+    };
+    let mut c = matrix(vec![0f64; m * m], m, m, Row);
+
+    unsafe {
+        matrixmultiply::dgemm(
+            m,
+            k,
+            m,
+            1f64,
+            data.ptr(),
+            cs,
+            rs,
+            data.ptr(),
+            rs,
+            cs,
+            0f64,
+            c.mut_ptr(),
+            m as isize,
+            1isize,
+        )
+    }
+    c
+}
+
+/// Multiply every matrix of a batch by the same matrix
+///
+/// # Description
+/// All matrices of `batch` should have the same size `r x k` and `rhs` should be `k x n`.
+/// The batch is stacked into one `(batch.len() * r) x k` matrix (see `batched_matmul_stacked`),
+/// so a single `dgemm` call (multi-threaded by `matrixmultiply`) handles the whole batch.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let w = ml_matrix("1 0;1 1");
+/// let batch = vec![ml_matrix("1 2;3 4"), ml_matrix("0 1;1 0")];
+/// let out = batched_matmul(&batch, &w);
+/// assert_eq!(out[0], &batch[0] * &w);
+/// assert_eq!(out[1], &batch[1] * &w);
+/// ```
+pub fn batched_matmul(batch: &[Matrix], rhs: &Matrix) -> Vec<Matrix> {
+    if batch.is_empty() {
+        return vec![];
+    }
+    let (r, k) = (batch[0].row, batch[0].col);
+    assert!(
+        batch.iter().all(|m| m.row == r && m.col == k),
+        "All matrices in batch should have same size ({}x{})",
+        r,
+        k
+    );
+    let mut data = Vec::with_capacity(batch.len() * r * k);
+    for m in batch {
+        match m.shape {
+            Row => data.extend_from_slice(&m.data),
+            Col => data.extend(m.change_shape().data),
+        }
+    }
+    let stack = matrix(data, batch.len() * r, k, Row);
+    let out = batched_matmul_stacked(&stack, rhs);
+    let n = rhs.col;
+    if r == 0 || n == 0 {
+        return vec![zeros_shape(r, n, Row); batch.len()];
+    }
+    out.data
+        .chunks(r * n)
+        .map(|c| matrix(c.to_vec(), r, n, Row))
+        .collect()
+}
+
+/// Batched matrix multiply for a stack of matrices
+///
+/// # Description
+/// A stack of `b` matrices of size `r x k` is stored as one `(b * r) x k` matrix
+/// (the `i`-th matrix occupies rows `i*r .. (i+1)*r`).
+/// The result is the stack of products with `rhs`, i.e. a `(b * r) x n` matrix in `Row` shape.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let stack = ml_matrix("1 2;3 4;0 1;1 0"); // two 2x2 matrices
+/// let w = ml_matrix("1 0;1 1");
+/// let out = batched_matmul_stacked(&stack, &w);
+/// assert_eq!(out, ml_matrix("3 2;7 4;1 1;1 0"));
+/// ```
+pub fn batched_matmul_stacked(stack: &Matrix, rhs: &Matrix) -> Matrix {
+    assert_eq!(
+        stack.col, rhs.row,
+        "Matrix dimensions should be compatible ({}x{} vs {}x{})",
+        stack.row, stack.col, rhs.row, rhs.col
+    );
+    let mut c = matrix(vec![0f64; stack.row * rhs.col], stack.row, rhs.col, Row);
+    if c.data.is_empty() || stack.col == 0 {
+        return c;
+    }
+    gemm(1f64, stack, rhs, 0f64, &mut c);
+    c
+}
+
 //fn matmul(a: &Matrix, b: &Matrix) -> Matrix {
 //    match (a.row, a.col) {
 //        (p, q) if p <= 100 && q <= 100 => {
@@ -4712,6 +6443,7 @@ pub fn gen_householder(a: &Vec<f64>) -> Matrix {
 
 /// LU via Gaussian Elimination with Partial Pivoting
 #[allow(dead_code)]
+#[cfg(feature = "std")]
 fn gepp(m: &mut Matrix) -> Vec<usize> {
     let mut r = vec![0usize; m.col - 1];
     for k in 0..(m.col - 1) {
@@ -4807,14 +6539,14 @@ fn gecp(m: &mut Matrix) -> (Vec<usize>, Vec<usize>) {
         // Interchange rows
         for j in k..n {
             unsafe {
-                std::ptr::swap(&mut m[(k, j)], &mut m[(r_k, j)]);
+                core::ptr::swap(&mut m[(k, j)], &mut m[(r_k, j)]);
             }
         }
 
         // Interchange cols
         for i in 0..n {
             unsafe {
-                std::ptr::swap(&mut m[(i, k)], &mut m[(i, s_k)]);
+                core::ptr::swap(&mut m[(i, k)], &mut m[(i, s_k)]);
             }
         }
 
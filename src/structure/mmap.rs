@@ -0,0 +1,93 @@
+//! Memory-mapped matrix (`mmap` feature)
+//!
+//! ## Description
+//!
+//! * `MmapMatrix` is a read-only view of a binary matrix file written by `Matrix::to_bytes`.
+//! * Only the touched pages are loaded into memory, so it can handle matrices larger than RAM.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use peroxide::fuga::*;
+//! use std::fs;
+//!
+//! # #[cfg(feature = "mmap")]
+//! # {
+//! let a = ml_matrix("1 2;3 4");
+//! let path = std::env::temp_dir().join("peroxide_doc_mmap.bin");
+//! let path = path.to_str().unwrap();
+//! fs::write(path, a.to_bytes()).unwrap();
+//!
+//! let m = MmapMatrix::open(path).unwrap();
+//! assert_eq!(m.at(1, 0), 3f64);
+//! assert_eq!(m.row(0), vec![1f64, 2f64]);
+//! assert_eq!(m.to_matrix(), a);
+//! # drop(m);
+//! # fs::remove_file(path).unwrap();
+//! # }
+//! ```
+
+use crate::structure::matrix::{matrix, parse_matrix_bytes_header, Matrix, Shape, MATRIX_BYTES_HEADER};
+use memmap2::Mmap;
+use std::convert::TryInto;
+use std::fs::File;
+
+/// Read-only memory-mapped matrix
+#[derive(Debug)]
+pub struct MmapMatrix {
+    mmap: Mmap,
+    pub row: usize,
+    pub col: usize,
+    pub shape: Shape,
+}
+
+impl MmapMatrix {
+    /// Memory-map a binary file produced by `Matrix::to_bytes`
+    pub fn open(file_path: &str) -> anyhow::Result<Self> {
+        let file = File::open(file_path)?;
+        // Safety: The map is read-only. Modifying the file while it is mapped is undefined behavior.
+        let mmap = unsafe { Mmap::map(&file)? };
+        let (row, col, shape) = parse_matrix_bytes_header(&mmap)?;
+        Ok(MmapMatrix {
+            mmap,
+            row,
+            col,
+            shape,
+        })
+    }
+
+    /// Element at `(i, j)`
+    pub fn at(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.row && j < self.col, "Index out of range");
+        let idx = match self.shape {
+            Shape::Row => i * self.col + j,
+            Shape::Col => i + j * self.row,
+        };
+        self.at_raw(idx)
+    }
+
+    /// Extract row
+    pub fn row(&self, index: usize) -> Vec<f64> {
+        assert!(index < self.row, "Index out of range");
+        (0..self.col).map(|j| self.at(index, j)).collect()
+    }
+
+    /// Extract column
+    pub fn col(&self, index: usize) -> Vec<f64> {
+        assert!(index < self.col, "Index out of range");
+        (0..self.row).map(|i| self.at(i, index)).collect()
+    }
+
+    /// Load whole matrix into memory
+    pub fn to_matrix(&self) -> Matrix {
+        let data = (0..self.row * self.col)
+            .map(|k| self.at_raw(k))
+            .collect::<Vec<f64>>();
+        matrix(data, self.row, self.col, self.shape)
+    }
+
+    fn at_raw(&self, k: usize) -> f64 {
+        let start = MATRIX_BYTES_HEADER + 8 * k;
+        f64::from_le_bytes(self.mmap[start..start + 8].try_into().unwrap())
+    }
+}
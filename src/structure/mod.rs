@@ -4,13 +4,22 @@
 //! * Vector
 //! * Automatic derivatives
 //! * Polynomial
+//! * Interval
 //! * DataFrame
 //! * Multinomial (not yet implemented)
 
+#[cfg(feature = "std")]
 pub mod ad;
+#[cfg(feature = "std")]
 pub mod dataframe;
+pub mod interval;
 pub mod matrix;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "std")]
 pub mod multinomial;
+#[cfg(feature = "std")]
 pub mod polynomial;
+#[cfg(feature = "std")]
 pub mod sparse;
 pub mod vector;
@@ -275,7 +275,12 @@ use crate::traits::{
     mutable::MutFP,
     pointer::{Oxide, Redox, RedoxCommon},
 };
-use std::cmp::min;
+#[cfg(feature = "std")]
+use crate::{special::function::softplus, traits::math::StableExpLogOps};
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+use core::cmp::min;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 impl FPVector for Vec<f64> {
     type Scalar = f64;
@@ -323,6 +328,55 @@ impl FPVector for Vec<f64> {
         self.iter().fold(init.into(), |x, &y| f(x, y))
     }
 
+    /// reduce without initial value for `Vec<f64>`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1,2,3,4,5);
+    ///     assert_eq!(a.reduce1(|x,y| x * y), Some(120f64));
+    ///
+    ///     let b: Vec<f64> = vec![];
+    ///     assert_eq!(b.reduce1(|x,y| x * y), None);
+    /// }
+    /// ```
+    fn reduce1<F>(&self, f: F) -> Option<f64>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        self.iter().copied().reduce(f)
+    }
+
+    /// scan for `Vec<f64>`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1,2,3,4);
+    ///     assert_eq!(a.scan(0, |x,y| x + y), c!(1,3,6,10));
+    /// }
+    /// ```
+    fn scan<F, T>(&self, init: T, f: F) -> Vec<f64>
+    where
+        F: Fn(f64, f64) -> f64,
+        T: Into<f64>,
+    {
+        self.iter()
+            .scan(init.into(), |acc, &x| {
+                *acc = f(*acc, x);
+                Some(*acc)
+            })
+            .collect()
+    }
+
     fn zip_with<F>(&self, f: F, other: &Vec<f64>) -> Vec<f64>
     where
         F: Fn(f64, f64) -> f64,
@@ -333,6 +387,43 @@ impl FPVector for Vec<f64> {
             .collect::<Vec<f64>>()
     }
 
+    /// zip_with for three `Vec<f64>`
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let a = c!(1,2,3);
+    ///     let b = c!(4,5,6);
+    ///     let c = c!(7,8,9);
+    ///     assert_eq!(a.zip_with3(|x,y,z| x * y + z, &b, &c), c!(11,18,27));
+    /// }
+    /// ```
+    fn zip_with3<F>(&self, f: F, b: &Vec<f64>, c: &Vec<f64>) -> Vec<f64>
+    where
+        F: Fn(f64, f64, f64) -> f64,
+    {
+        assert!(
+            self.len() == b.len() && self.len() == c.len(),
+            "zip_with3: length mismatch"
+        );
+        (0..self.len()).map(|i| f(self[i], b[i], c[i])).collect()
+    }
+
+    fn zip_with4<F>(&self, f: F, b: &Vec<f64>, c: &Vec<f64>, d: &Vec<f64>) -> Vec<f64>
+    where
+        F: Fn(f64, f64, f64, f64) -> f64,
+    {
+        assert!(
+            self.len() == b.len() && self.len() == c.len() && self.len() == d.len(),
+            "zip_with4: length mismatch"
+        );
+        (0..self.len()).map(|i| f(self[i], b[i], c[i], d[i])).collect()
+    }
+
     /// Filter for `Vec<f64>`
     ///
     /// # Examples
@@ -605,6 +696,60 @@ impl Algorithm for Vec<f64> {
             .0
     }
 
+    /// Index of the first maximum
+    ///
+    /// # Description
+    /// Unlike `arg_max`, panics for an empty vector instead of returning `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(1,7,2,7,3);
+    ///     assert_eq!(v.argmax(), 1);
+    /// }
+    /// ```
+    fn argmax(&self) -> usize {
+        assert!(!self.is_empty(), "Can't find argmax of an empty vector");
+        let mut idx = 0usize;
+        for (i, &x) in self.iter().enumerate().skip(1) {
+            if x > self[idx] {
+                idx = i;
+            }
+        }
+        idx
+    }
+
+    /// Index of the first minimum
+    ///
+    /// # Description
+    /// Unlike `arg_min`, panics for an empty vector instead of returning `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// #[macro_use]
+    /// extern crate peroxide;
+    /// use peroxide::fuga::*;
+    ///
+    /// fn main() {
+    ///     let v = c!(3,1,2,1,7);
+    ///     assert_eq!(v.argmin(), 1);
+    /// }
+    /// ```
+    fn argmin(&self) -> usize {
+        assert!(!self.is_empty(), "Can't find argmin of an empty vector");
+        let mut idx = 0usize;
+        for (i, &x) in self.iter().enumerate().skip(1) {
+            if x < self[idx] {
+                idx = i;
+            }
+        }
+        idx
+    }
+
     fn max(&self) -> f64 {
         #[cfg(feature = "O3")]
         {
@@ -725,6 +870,21 @@ impl Oxide for Vec<f64> {
     }
 }
 
+#[cfg(feature = "std")]
+impl StableExpLogOps for Vec<f64> {
+    fn log1p(&self) -> Self {
+        self.fmap(|x| x.ln_1p())
+    }
+
+    fn expm1(&self) -> Self {
+        self.fmap(|x| x.exp_m1())
+    }
+
+    fn softplus(&self) -> Self {
+        self.fmap(softplus)
+    }
+}
+
 impl VectorProduct for Vec<f64> {
     fn cross(&self, other: &Self) -> Self {
         assert_eq!(self.len(), other.len());
@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 pub trait FloatWithPrecision {
     fn round_with_precision(&self, precision: usize) -> Self;
     fn floor_with_precision(&self, precision: usize) -> Self;
@@ -1,4 +1,5 @@
 use crate::structure::matrix::Matrix;
+use alloc::vec::Vec;
 
 /// Functional Programming tools for Vector
 pub trait FPVector {
@@ -8,12 +9,29 @@ pub trait FPVector {
     where
         F: Fn(Self::Scalar) -> Self::Scalar;
     fn reduce<F, T>(&self, init: T, f: F) -> Self::Scalar
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
+        T: Into<Self::Scalar>;
+    /// `reduce` without initial value (`None` for empty vector)
+    fn reduce1<F>(&self, f: F) -> Option<Self::Scalar>
+    where
+        F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar;
+    /// Running accumulations of `reduce` (same length as `self`, `init` excluded)
+    fn scan<F, T>(&self, init: T, f: F) -> Self
     where
         F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
         T: Into<Self::Scalar>;
     fn zip_with<F>(&self, f: F, other: &Self) -> Self
     where
         F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar;
+    /// Element-wise map over three vectors in a single pass (panics for unequal lengths)
+    fn zip_with3<F>(&self, f: F, b: &Self, c: &Self) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar, Self::Scalar) -> Self::Scalar;
+    /// Element-wise map over four vectors in a single pass (panics for unequal lengths)
+    fn zip_with4<F>(&self, f: F, b: &Self, c: &Self, d: &Self) -> Self
+    where
+        F: Fn(Self::Scalar, Self::Scalar, Self::Scalar, Self::Scalar) -> Self::Scalar;
     fn filter<F>(&self, f: F) -> Self
     where
         F: Fn(Self::Scalar) -> bool;
@@ -1,9 +1,19 @@
+use alloc::vec::Vec;
+
 /// Some algorithms for Vector
 pub trait Algorithm {
     fn rank(&self) -> Vec<usize>;
     fn sign(&self) -> f64;
     fn arg_max(&self) -> usize;
     fn arg_min(&self) -> usize;
+    /// Index of the maximum (defaults to `arg_max`)
+    fn argmax(&self) -> usize {
+        self.arg_max()
+    }
+    /// Index of the minimum (defaults to `arg_min`)
+    fn argmin(&self) -> usize {
+        self.arg_min()
+    }
     fn max(&self) -> f64;
     fn min(&self) -> f64;
     fn swap_with_perm(&mut self, p: &Vec<(usize, usize)>);
@@ -97,3 +97,14 @@ impl Normed for f64 {
         self / self.abs()
     }
 }
+
+/// Numerically stable exponential & logarithmic functions (element-wise)
+///
+/// * `log1p` : `ln(1 + x)`
+/// * `expm1` : `exp(x) - 1`
+/// * `softplus` : `ln(1 + exp(x))` (see `special::function::softplus`)
+pub trait StableExpLogOps {
+    fn log1p(&self) -> Self;
+    fn expm1(&self) -> Self;
+    fn softplus(&self) -> Self;
+}
@@ -1,4 +1,5 @@
 use crate::structure::matrix::Shape;
+use alloc::vec::Vec;
 
 pub trait MutFP {
     type Scalar;
@@ -23,11 +23,30 @@
 //!         return x.powi(2)
 //!     }
 //!     ```
+//!
+//! ## `MatrixScalar` trait
+//!
+//! * `MatrixScalar` is a trait for the element type of a generic `Matrix<T>`
+//! * It is implemented for `f64`, `f32`, primitive integers and `Interval`
+//!
+//!     ```rust
+//!     use peroxide::fuga::*;
+//!
+//!     let a = Matrix::from_vec(vec![1i64, 2, 3, 4], 2, 2, Row);
+//!     let b = Matrix::from_vec(vec![4i64, 3, 2, 1], 2, 2, Row);
+//!     assert_eq!(&a + &b, Matrix::from_vec(vec![5i64; 4], 2, 2, Row));
+//!     ```
 
-use std::ops::{Neg, Add, Sub, Mul, Div};
+use core::fmt::Debug;
+use core::ops::{Add, Sub, Mul, Div};
+#[cfg(feature = "std")]
+use core::ops::Neg;
+#[cfg(feature = "std")]
 use crate::structure::ad::AD;
+#[cfg(feature = "std")]
 use peroxide_num::{PowOps, TrigOps, ExpLogOps};
 
+#[cfg(feature = "std")]
 pub trait Real:
     PowOps
     + TrigOps
@@ -50,6 +69,7 @@ pub trait Real:
     fn to_ad(&self) -> AD;
 }
 
+#[cfg(feature = "std")]
 impl Real for f64 {
     fn to_f64(&self) -> f64 {
         *self
@@ -64,6 +84,7 @@ impl Real for f64 {
     }
 }
 
+#[cfg(feature = "std")]
 impl Real for AD {
     fn to_f64(&self) -> f64 {
         self.x()
@@ -77,3 +98,37 @@ impl Real for AD {
         *self
     }
 }
+
+/// Element type of generic `Matrix<T>`
+pub trait MatrixScalar:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + PartialOrd
+    + Debug
+    + Default
+    + Clone
+    + Copy
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_matrix_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl MatrixScalar for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+
+                fn one() -> Self {
+                    1 as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_scalar!(f64, f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
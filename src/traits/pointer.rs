@@ -44,13 +44,16 @@
 //!
 //! `ox()` and `red()` come from oxidation and reduction.
 use crate::structure::matrix::{Matrix, Shape};
+#[cfg(feature = "std")]
 use crate::structure::sparse::SPMatrix;
+#[cfg(feature = "std")]
 use crate::structure::ad::AD;
 use crate::traits::{
     fp::FPVector,
     math::{LinearOp, Vector},
 };
-use std::ops::{Add, Deref, Div, Mul, Sub};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::ops::{Add, Deref, Div, Mul, Sub};
 
 // =============================================================================
 // Redox Structure
@@ -87,6 +90,7 @@ impl RedoxCommon for Redox<Vec<f64>> {
     }
 }
 
+#[cfg(feature = "std")]
 impl RedoxCommon for Redox<Vec<AD>> {
     type ToRedox = Vec<AD>;
     fn from_vec(vec: Self::ToRedox) -> Self {
@@ -234,6 +238,7 @@ impl Mul<Redox<Vec<f64>>> for &Matrix {
 }
 
 /// Matrix multiplication with Redox
+#[cfg(feature = "std")]
 impl Mul<Redox<Vec<f64>>> for SPMatrix {
     type Output = Redox<Vec<f64>>;
     fn mul(self, rhs: Redox<Vec<f64>>) -> Self::Output {
@@ -243,6 +248,7 @@ impl Mul<Redox<Vec<f64>>> for SPMatrix {
     }
 }
 
+#[cfg(feature = "std")]
 impl Mul<Redox<Vec<f64>>> for &SPMatrix {
     type Output = Redox<Vec<f64>>;
 
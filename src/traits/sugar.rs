@@ -1,7 +1,8 @@
 use crate::structure::matrix::{Matrix, Shape, matrix};
 use crate::traits::fp::FPVector;
 use crate::util::non_macro::zeros_shape;
-use std::ops::{Add, Sub, Mul, Div};
+use alloc::vec::Vec;
+use core::ops::{Add, Sub, Mul, Div};
 
 /// Syntactic sugar for Vector operations
 pub trait VecOps: Sized + FPVector 
@@ -36,6 +37,65 @@ where Self::Scalar: Copy + Clone
     fn div_s(&self, s: Self::Scalar) -> Self {
         self.fmap(|x| x / s)
     }
+    /// Longest prefix whose elements satisfy `f`
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = vec![1f64, 2f64, 5f64, 1f64];
+    /// assert_eq!(a.take_while(|x| x < 3f64), vec![1f64, 2f64]);
+    /// assert_eq!(a.drop_while(|x| x < 3f64), vec![5f64, 1f64]);
+    /// ```
+    fn take_while<F>(&self, f: F) -> Self
+    where
+        Self: AsRef<[Self::Scalar]> + From<Vec<Self::Scalar>>,
+        F: Fn(Self::Scalar) -> bool,
+    {
+        Self::from(self.as_ref().iter().copied().take_while(|&x| f(x)).collect::<Vec<_>>())
+    }
+    /// Remainder after removing the longest prefix whose elements satisfy `f`
+    fn drop_while<F>(&self, f: F) -> Self
+    where
+        Self: AsRef<[Self::Scalar]> + From<Vec<Self::Scalar>>,
+        F: Fn(Self::Scalar) -> bool,
+    {
+        Self::from(self.as_ref().iter().copied().skip_while(|&x| f(x)).collect::<Vec<_>>())
+    }
+    /// Split into pieces of size `n` (last one may be shorter)
+    ///
+    /// Named `chunks_vec` so that it does not shadow `slice::chunks` for `Vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = vec![1f64, 2f64, 3f64, 4f64, 5f64];
+    /// assert_eq!(a.chunks_vec(2), vec![vec![1f64, 2f64], vec![3f64, 4f64], vec![5f64]]);
+    /// ```
+    fn chunks_vec(&self, n: usize) -> Vec<Self>
+    where Self: AsRef<[Self::Scalar]> + From<Vec<Self::Scalar>>
+    {
+        assert!(n > 0, "Chunk size should be positive");
+        self.as_ref().chunks(n).map(|c| Self::from(c.to_vec())).collect()
+    }
+    /// Overlapping windows of size `n` (empty if `n` exceeds the length)
+    ///
+    /// Named `windows_vec` so that it does not shadow `slice::windows` for `Vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use peroxide::fuga::*;
+    ///
+    /// let a = vec![1f64, 2f64, 3f64, 4f64];
+    /// assert_eq!(a.windows_vec(3), vec![vec![1f64, 2f64, 3f64], vec![2f64, 3f64, 4f64]]);
+    /// ```
+    fn windows_vec(&self, n: usize) -> Vec<Self>
+    where Self: AsRef<[Self::Scalar]> + From<Vec<Self::Scalar>>
+    {
+        assert!(n > 0, "Window size should be positive");
+        self.as_ref().windows(n).map(|w| Self::from(w.to_vec())).collect()
+    }
 }
 
 pub trait Scalable {
@@ -57,6 +117,25 @@ pub trait ConvToMat {
     fn to_row(&self) -> Matrix;
 }
 
+/// Cumulative operations & consecutive differences
+///
+/// * `cumsum`, `cumprod` : same length as input
+/// * `diff` : one element shorter than input (empty for empty input)
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// assert_eq!(vec![1f64, 2f64, 3f64].cumsum(), vec![1f64, 3f64, 6f64]);
+/// assert_eq!(vec![1f64, 2f64, 3f64].cumprod(), vec![1f64, 2f64, 6f64]);
+/// assert_eq!(vec![1f64, 2f64, 4f64].diff(), vec![1f64, 2f64]);
+/// ```
+pub trait CumOps {
+    fn cumsum(&self) -> Self;
+    fn cumprod(&self) -> Self;
+    fn diff(&self) -> Self;
+}
+
 // =============================================================================
 // Implementations
 // =============================================================================
@@ -454,3 +533,17 @@ impl ConvToMat for Vec<f64> {
         matrix(self.clone(), 1, self.len(), Shape::Row)
     }
 }
+
+impl CumOps for Vec<f64> {
+    fn cumsum(&self) -> Self {
+        FPVector::scan(self, 0f64, |x, y| x + y)
+    }
+
+    fn cumprod(&self) -> Self {
+        FPVector::scan(self, 1f64, |x, y| x * y)
+    }
+
+    fn diff(&self) -> Self {
+        self.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+}
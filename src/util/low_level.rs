@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 pub unsafe fn copy_vec_ptr(dst: &mut Vec<*mut f64>, src: &Vec<f64>) {
     assert_eq!(dst.len(), src.len(), "Should use same length vectors");
     for (&mut p, &s) in dst.iter_mut().zip(src) {
@@ -8,7 +10,7 @@ pub unsafe fn copy_vec_ptr(dst: &mut Vec<*mut f64>, src: &Vec<f64>) {
 pub unsafe fn swap_vec_ptr(lhs: &mut Vec<*mut f64>, rhs: &mut Vec<*mut f64>) {
     assert_eq!(lhs.len(), rhs.len(), "Should use same length vectors");
     for (&mut l, &mut r) in lhs.iter_mut().zip(rhs.iter_mut()) {
-        std::ptr::swap(l, r);
+        core::ptr::swap(l, r);
     }
 }
 
@@ -1,5 +1,6 @@
 //! Utility - plot, print, pickle and etc.
 
+#[cfg(feature = "std")]
 pub mod api;
 pub mod non_macro;
 
@@ -7,7 +8,10 @@ pub mod non_macro;
 pub mod plot;
 
 pub mod low_level;
+#[cfg(feature = "std")]
 pub mod print;
 pub mod useful;
+#[cfg(feature = "std")]
 pub mod wrapper;
+#[cfg(feature = "std")]
 pub mod writer;
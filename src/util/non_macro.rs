@@ -4,6 +4,8 @@
 //!
 //! - seq
 //! - seq_with_precision
+//! - seq_int
+//! - range_usize
 //! - rbind
 //! - cbind
 //!
@@ -15,8 +17,12 @@
 //! - zeros_shape
 //! - linspace
 //! - linspace_with_precision
+//! - piecewise_linspace
+//! - tabulate
+//! - tabulate_2d
 //! - rand
 //! - rand_with_rng
+//! - rand_from
 //!
 //! # Numpy like non-macro functions
 //!
@@ -31,22 +37,29 @@
 
 extern crate rand;
 use self::rand::prelude::*;
+#[cfg(feature = "std")]
+use crate::statistics::{dist::RNG, rand::global_rng};
 use crate::structure::{
     matrix::Shape::{Col, Row},
     matrix::{matrix, Matrix, Shape},
 };
 use crate::traits::float::FloatWithPrecision;
+use alloc::{vec, vec::Vec};
 use anyhow::{Result, bail};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 #[derive(Debug, Copy, Clone)]
 pub enum ConcatenateError {
     DifferentLength,
+    Empty,
 }
 
-impl std::fmt::Display for ConcatenateError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for ConcatenateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
             ConcatenateError::DifferentLength => write!(f, "To concatenate, vectors or matrices must have the same length"),
+            ConcatenateError::Empty => write!(f, "To concatenate, at least one matrix is required"),
         }
     }
 }
@@ -88,6 +101,72 @@ where
     v
 }
 
+/// R like seq function for integers
+///
+/// # Description
+/// Inclusive sequence `start, start + step, ...` up to `end` with exact integer arithmetic.
+/// A negative `step` gives a decreasing sequence.
+/// If `end` cannot be reached in the direction of `step`, the result is empty.
+///
+/// # Panics
+/// If `step` is zero.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = seq_int(1, 10, 2);
+/// assert_eq!(a, vec![1i64, 3, 5, 7, 9]);
+///
+/// let b = seq_int(1, 1, 1);
+/// assert_eq!(b, vec![1i64]);
+///
+/// let c = seq_int(0, 5, 10);
+/// assert_eq!(c, vec![0i64]);
+///
+/// let d = seq_int(3, -3, -3);
+/// assert_eq!(d, vec![3i64, 0, -3]);
+/// ```
+pub fn seq_int(start: i64, end: i64, step: i64) -> Vec<i64> {
+    assert_ne!(step, 0, "Step should be nonzero");
+    let mut v = vec![];
+    let mut x = start;
+    while (step > 0 && x <= end) || (step < 0 && x >= end) {
+        v.push(x);
+        match x.checked_add(step) {
+            Some(y) => x = y,
+            None => break,
+        }
+    }
+    v
+}
+
+/// Inclusive sequence of `usize` (for indices)
+///
+/// # Panics
+/// If `step` is zero.
+///
+/// # Example
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = range_usize(0, 10, 3);
+/// assert_eq!(a, vec![0usize, 3, 6, 9]);
+///
+/// let b = range_usize(2, 2, 1);
+/// assert_eq!(b, vec![2usize]);
+///
+/// let c = range_usize(0, 5, 10);
+/// assert_eq!(c, vec![0usize]);
+/// ```
+pub fn range_usize(start: usize, end: usize, step: usize) -> Vec<usize> {
+    assert_ne!(step, 0, "Step should be nonzero");
+    if start > end {
+        return vec![];
+    }
+    (start..=end).step_by(step).collect()
+}
+
 /// Seq with Precision
 ///
 /// # Example
@@ -311,14 +390,115 @@ where
     v
 }
 
+/// Piecewise linspace - concatenate linspaces sharing their boundaries
+///
+/// # Description
+///
+/// For `n+1` breakpoints and `n` counts, generate `linspace(breakpoints[i], breakpoints[i+1], counts[i])`
+/// for each segment and join them, removing the duplicated interior endpoints.
+/// Every count should be at least 2, so that each segment contains both of its breakpoints.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = piecewise_linspace(&[0f64, 1f64, 3f64], &[3, 5]);
+/// assert_eq!(a, vec![0f64, 0.5, 1f64, 1.5, 2f64, 2.5, 3f64]);
+/// assert!(a.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub fn piecewise_linspace(breakpoints: &[f64], counts: &[usize]) -> Vec<f64> {
+    assert_eq!(
+        breakpoints.len(),
+        counts.len() + 1,
+        "Number of breakpoints ({}) should be number of counts ({}) + 1",
+        breakpoints.len(),
+        counts.len()
+    );
+    assert!(
+        counts.iter().all(|&c| c >= 2),
+        "Each count should be at least 2 to include both breakpoints (counts: {:?})",
+        counts
+    );
+
+    let mut v: Vec<f64> = Vec::new();
+    for (i, &count) in counts.iter().enumerate() {
+        let segment = linspace(breakpoints[i], breakpoints[i + 1], count);
+        if i == 0 {
+            v.extend(segment);
+        } else {
+            v.extend(segment.into_iter().skip(1));
+        }
+    }
+    v
+}
+
+/// Tabulate function on a `linspace` grid
+///
+/// # Description
+///
+/// Returns `(x, f(x))` with `x = linspace(start, end, n)`,
+/// e.g. to build lookup tables or nodes for interpolation.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (x, y) = tabulate(|t| t * t, 0, 2, 5);
+/// assert_eq!(x, vec![0f64, 0.5, 1f64, 1.5, 2f64]);
+/// assert_eq!(y, vec![0f64, 0.25, 1f64, 2.25, 4f64]);
+/// ```
+pub fn tabulate<F, S, T>(f: F, start: S, end: T, n: usize) -> (Vec<f64>, Vec<f64>)
+where
+    F: Fn(f64) -> f64,
+    S: Into<f64> + Copy,
+    T: Into<f64> + Copy,
+{
+    let x = linspace(start, end, n);
+    let y = x.iter().map(|&t| f(t)).collect();
+    (x, y)
+}
+
+/// Tabulate function of two variables on a `linspace` grid
+///
+/// # Description
+///
+/// For `x = linspace(x_range.0, x_range.1, x_range.2)` and `y` likewise,
+/// returns `(x, y, z)` where `z[(i, j)] = f(x[i], y[j])`.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let (x, y, z) = tabulate_2d(|s, t| s * t, (1f64, 2f64, 2), (0f64, 2f64, 3));
+/// assert_eq!(x, vec![1f64, 2f64]);
+/// assert_eq!(y, vec![0f64, 1f64, 2f64]);
+/// assert_eq!(z, ml_matrix("0 1 2;0 2 4"));
+/// ```
+pub fn tabulate_2d<F: Fn(f64, f64) -> f64>(
+    f: F,
+    x_range: (f64, f64, usize),
+    y_range: (f64, f64, usize),
+) -> (Vec<f64>, Vec<f64>, Matrix) {
+    let x = linspace(x_range.0, x_range.1, x_range.2);
+    let y = linspace(y_range.0, y_range.1, y_range.2);
+    let mut z = zeros(x.len(), y.len());
+    for (i, &s) in x.iter().enumerate() {
+        for (j, &t) in y.iter().enumerate() {
+            z[(i, j)] = f(s, t);
+        }
+    }
+    (x, y, z)
+}
+
 /// Rand matrix
 ///
 /// # Description
 ///
-/// Range = from 0 to 1
+/// Range = from 0 to 1 (uses `global_rng`, see `set_global_seed`)
+#[cfg(feature = "std")]
 pub fn rand(r: usize, c: usize) -> Matrix {
     let mut m = zeros(r, c);
-    let mut rng = thread_rng();
+    let mut rng = global_rng();
     for i in 0..r {
         for j in 0..c {
             m[(i, j)] = rng.gen_range(0f64..=1f64);
@@ -342,6 +522,26 @@ pub fn rand_with_rng<R: Rng>(r: usize, c: usize, rng: &mut R) -> Matrix {
     m
 }
 
+/// Matrix of i.i.d. samples from any distribution
+///
+/// # Description
+///
+/// Samples are drawn with `dist.sample_with_rng` and filled in row-major order.
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let mut rng = smallrng_from_seed(42);
+/// let m = rand_from(3, 4, &Gamma(2, 1), &mut rng);
+/// assert_eq!((m.row, m.col), (3, 4));
+/// assert!(m.data.iter().all(|&x| x > 0f64));
+/// ```
+#[cfg(feature = "std")]
+pub fn rand_from<D: RNG, R: Rng + Clone>(r: usize, c: usize, dist: &D, rng: &mut R) -> Matrix {
+    matrix(dist.sample_with_rng(rng, r * c), r, c, Row)
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Numpy like non-macro functions
 // └─────────────────────────────────────────────────────────┘
@@ -408,17 +608,28 @@ pub fn row_stack(v: &[Vec<f64>]) -> Result<Matrix> {
 //  Haskell like non-macro functions
 // └─────────────────────────────────────────────────────────┘
 /// Concatenate two vectors into one
-pub fn concat<T: Clone + Copy>(v1: &[T], v2: &[T]) -> Vec<T> {
-    let mut v = v1.to_vec();
-    v.extend_from_slice(v2);
+///
+/// # Examples
+/// ```
+/// use peroxide::fuga::*;
+///
+/// let a = vec!["a".to_string(), "b".to_string()];
+/// let b = vec!["c".to_string()];
+/// assert_eq!(concat(&a, &b), vec!["a", "b", "c"]);
+/// ```
+pub fn concat<T: Clone>(v1: &[T], v2: &[T]) -> Vec<T> {
+    let mut v = Vec::with_capacity(v1.len() + v2.len());
+    v.extend(v1.iter().cloned());
+    v.extend(v2.iter().cloned());
 
     v
 }
 
 /// Concatenate a value and vector
-pub fn cat<T: Clone + Copy + Default>(val: T, vec: &[T]) -> Vec<T> {
-    let mut v = vec![val];
-    v.extend_from_slice(vec);
+pub fn cat<T: Clone>(val: T, vec: &[T]) -> Vec<T> {
+    let mut v = Vec::with_capacity(vec.len() + 1);
+    v.push(val);
+    v.extend(vec.iter().cloned());
 
     v
 }
@@ -1,6 +1,7 @@
 //! Useful missing tools
 
-use std::ops::Range;
+use alloc::{format, string::String, vec::Vec};
+use core::ops::Range;
 
 // =============================================================================
 // Fundamental Utils
@@ -1,5 +1,6 @@
 extern crate rand;
 use rand::prelude::*;
+use crate::statistics::rand::global_rng;
 
 // =============================================================================
 // Random Wrapper
@@ -13,7 +14,7 @@ pub trait SampleRNG {
 impl SampleRNG for Vec<usize> {
     type Item = usize;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -21,7 +22,7 @@ impl SampleRNG for Vec<usize> {
 impl SampleRNG for Vec<u32> {
     type Item = u32;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -29,7 +30,7 @@ impl SampleRNG for Vec<u32> {
 impl SampleRNG for Vec<u64> {
     type Item = u64;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -37,7 +38,7 @@ impl SampleRNG for Vec<u64> {
 impl SampleRNG for Vec<isize> {
     type Item = isize;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -45,7 +46,7 @@ impl SampleRNG for Vec<isize> {
 impl SampleRNG for Vec<i32> {
     type Item = i32;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -53,7 +54,7 @@ impl SampleRNG for Vec<i32> {
 impl SampleRNG for Vec<i64> {
     type Item = i64;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -61,7 +62,7 @@ impl SampleRNG for Vec<i64> {
 impl SampleRNG for Vec<f64> {
     type Item = f64;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -69,7 +70,7 @@ impl SampleRNG for Vec<f64> {
 impl SampleRNG for Vec<f32> {
     type Item = f32;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -77,7 +78,7 @@ impl SampleRNG for Vec<f32> {
 impl SampleRNG for Vec<char> {
     type Item = char;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -85,7 +86,7 @@ impl SampleRNG for Vec<char> {
 impl<'a> SampleRNG for Vec<&'a str> {
     type Item = &'a str;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.iter().map(|x| *x).choose_multiple(&mut rng, n)
     }
 }
@@ -93,7 +94,7 @@ impl<'a> SampleRNG for Vec<&'a str> {
 impl SampleRNG for String {
     type Item = char;
     fn sample(&self, n: usize) -> Vec<Self::Item> {
-        let mut rng = thread_rng();
+        let mut rng = global_rng();
         self.chars().choose_multiple(&mut rng, n)
     }
 }
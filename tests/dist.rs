@@ -8,3 +8,50 @@ fn test_binomial() {
     assert!(nearly_eq(b.mean(), 80f64));
     assert!(nearly_eq(b.var(), 16f64));
 }
+
+#[test]
+fn test_dist_population_statistics() {
+    let b = Binomial(100, 0.8);
+    assert_eq!(b.var_pop(), b.var());
+    assert_eq!(b.std_pop(), b.sd());
+
+    let t = StudentT(5f64);
+    assert_eq!(t.var_pop(), t.var());
+
+    let w = WeightedUniform::new(vec![1f64, 2f64], vec![0f64, 1f64, 3f64]).unwrap();
+    assert_eq!(w.var_pop(), w.var());
+    assert_eq!(w.std_pop(), w.sd());
+}
+
+#[test]
+fn test_dist_geometric_harmonic_mean() {
+    let euler_gamma = 0.5772156649015329;
+
+    let u = Uniform(1f64, 3f64);
+    assert!((u.geometric_mean() - (1.5 * 3f64.ln() - 1f64).exp()).abs() < 1e-12);
+    assert!((u.harmonic_mean() - 2f64 / 3f64.ln()).abs() < 1e-12);
+    assert!(Uniform(-1f64, 1f64).geometric_mean().is_nan());
+    assert_eq!(Uniform(0f64, 2f64).harmonic_mean(), 0f64);
+
+    // psi(2) - psi(5) = -(1/2 + 1/3 + 1/4)
+    let b = Beta(2f64, 3f64);
+    assert!((b.geometric_mean() - (-13f64 / 12f64).exp()).abs() < 1e-12);
+    assert!((b.harmonic_mean() - 0.25).abs() < 1e-12);
+
+    // psi(3) = 3/2 - euler_gamma
+    let g = Gamma(3f64, 2f64);
+    assert!((g.geometric_mean() - (1.5 - euler_gamma).exp() / 2f64).abs() < 1e-12);
+    assert!((g.harmonic_mean() - 1f64).abs() < 1e-12);
+    assert!((Gamma(0.5, 1f64).geometric_mean() - (-euler_gamma - 2f64 * 2f64.ln()).exp()).abs() < 1e-12);
+
+    assert_eq!(Binomial(10, 0.5).geometric_mean(), 0f64);
+    assert_eq!(Bernoulli(1f64).harmonic_mean(), 1f64);
+    assert!(Normal(1f64, 1f64).harmonic_mean().is_nan());
+
+    let w = WeightedUniform::new(vec![1f64, 1f64], vec![1f64, 2f64, 3f64]).unwrap();
+    assert!((w.geometric_mean() - u.geometric_mean()).abs() < 1e-12);
+    assert!((w.harmonic_mean() - u.harmonic_mean()).abs() < 1e-12);
+
+    let w = WeightedUniform::new(vec![1f64, 3f64], vec![1f64, 2f64, 4f64]).unwrap();
+    assert!(w.mean() >= w.geometric_mean() && w.geometric_mean() >= w.harmonic_mean());
+}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::essentials::*;
+
+#[test]
+fn test_essentials_only() {
+    let a = matrix(vec![1f64, 2f64, 3f64, 4f64], 2, 2, Row);
+    let b = ml_matrix("1 2;3 4");
+    assert_eq!(a, b);
+    assert_eq!(&a * &eye(2), a);
+    assert_eq!(zeros(2, 3).data, vec![0f64; 6]);
+
+    let x = seq(1, 5, 1);
+    let y = linspace(2, 10, 5);
+    assert_eq!(x.mean(), 3f64);
+    assert_eq!(x.var(), 2.5);
+    assert!((cor(&x, &y) - 1f64).abs() < 1e-12);
+    assert_eq!(cov(&x, &y), 5f64);
+    assert_eq!(x.dot(&c!(1, 1, 1, 1, 1)), 15f64);
+    assert_eq!(c!(3, 4).norm(Norm::L2), 5f64);
+    assert_eq!(a.mean(), c!(2, 3));
+}
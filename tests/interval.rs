@@ -0,0 +1,137 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_interval_division_encloses() {
+    let third = Interval::new(1.0, 1.0) / Interval::new(3.0, 3.0);
+    let nearest = 1f64 / 3f64;
+
+    // 1/3 is not representable: the enclosure is the two adjacent floats
+    assert!(third.lo < third.hi);
+    assert!(third.contains(nearest));
+    assert_eq!(third.lo.to_bits() + 1, third.hi.to_bits());
+
+    // Tight check with exact arithmetic: 3 * lo < 1 < 3 * hi
+    assert!(3f64.mul_add(third.lo, -1f64) < 0f64);
+    assert!(3f64.mul_add(third.hi, -1f64) > 0f64);
+
+    // Exact results are not widened
+    assert_eq!(
+        Interval::point(1f64) / Interval::point(4f64),
+        Interval::point(0.25)
+    );
+    assert_eq!(
+        Interval::new(1f64, 2f64) / Interval::new(-1f64, 1f64),
+        Interval::entire()
+    );
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let x = Interval::new(-1f64, 2f64);
+    let y = Interval::new(3f64, 4f64);
+    assert_eq!(x + y, Interval::new(2f64, 6f64));
+    assert_eq!(x - y, Interval::new(-5f64, -1f64));
+    assert_eq!(x * y, Interval::new(-4f64, 8f64));
+    assert_eq!(x * x, Interval::new(-2f64, 4f64));
+    assert_eq!(x.powi(2), Interval::new(0f64, 4f64));
+    assert_eq!(2f64 * x + 1f64, Interval::new(-1f64, 5f64));
+
+    // 0.1 + 0.2 != 0.3 in floating point, but the enclosure contains both candidates
+    let s = Interval::point(0.1) + Interval::point(0.2);
+    assert!(s.contains(0.1 + 0.2));
+    assert!(s.width() <= 2f64 * f64::EPSILON);
+
+    // Overflow gives a finite lower bound
+    let big = Interval::point(f64::MAX) + Interval::point(f64::MAX);
+    assert_eq!((big.lo, big.hi), (f64::MAX, f64::INFINITY));
+}
+
+#[test]
+fn test_interval_functions() {
+    let two = Interval::point(2f64).sqrt();
+    assert!(two.contains(2f64.sqrt()));
+    assert!(two.lo * two.lo <= 2f64 && 2f64 <= two.hi * two.hi);
+    assert_eq!(Interval::point(4f64).sqrt(), Interval::point(2f64));
+
+    let e = Interval::point(1f64).exp();
+    assert!(e.contains(std::f64::consts::E));
+    let l = e.ln();
+    assert!(l.contains(1f64));
+
+    // Extrema inside the interval
+    let x = Interval::new(1f64, 2f64);
+    assert_eq!(x.sin().hi, 1f64);
+    assert!(x.sin().contains(1f64.sin()));
+    let c = Interval::new(3f64, 3.5).cos();
+    assert_eq!(c.lo, -1f64);
+    assert!(c.contains(3.5f64.cos()));
+    assert_eq!(Interval::new(0f64, 10f64).sin(), Interval::new(-1f64, 1f64));
+
+    // Monotone piece
+    let m = Interval::new(0.1, 0.2).sin();
+    assert!(m.contains(0.1f64.sin()) && m.contains(0.2f64.sin()));
+    assert!(m.hi < 1f64);
+}
+
+#[test]
+fn test_interval_subnormal_encloses() {
+    // Scaling by a power of two is exact, so the bounds can be checked against exact
+    // products and quotients computed far from the subnormal range
+    let s = 2f64.powi(600);
+    let xs = [1e-160, 1.7e-160, 3.3e-161, 7.9e-155, 2.3e-170];
+    for &a in xs.iter() {
+        for &b in xs.iter() {
+            let p = Interval::point(a) * Interval::point(b);
+            let (ps, bs) = (a * s, b * s);
+            let exact = ps * bs;
+            let err = ps.mul_add(bs, -exact);
+            let (lo, hi) = (p.lo * s * s, p.hi * s * s);
+            assert!(lo < exact || (lo == exact && err >= 0f64));
+            assert!(hi > exact || (hi == exact && err <= 0f64));
+            assert!(p.lo < p.hi);
+
+            let q = Interval::point(a * 1e-150) / Interval::point(b * 1e160);
+            let exact = (a * 1e-150 * s) / (b * 1e160);
+            let err = (-exact).mul_add(b * 1e160, a * 1e-150 * s);
+            let (lo, hi) = (q.lo * s, q.hi * s);
+            assert!(lo < exact || (lo == exact && err >= 0f64));
+            assert!(hi > exact || (hi == exact && err <= 0f64));
+        }
+    }
+
+    let r = Interval::point(5e-324).sqrt();
+    assert!(r.lo < r.hi && r.contains(5e-324f64.sqrt()));
+}
+
+#[test]
+fn test_interval_matrix() {
+    let x = Interval::new(1f64, 2f64);
+    assert!(x < Interval::new(3f64, 4f64));
+    assert!(!(x < Interval::new(2f64, 3f64)) && !(x > Interval::new(2f64, 3f64)));
+
+    let a = Matrix::from_vec(
+        vec![0.1, 0.2, 0.3, 0.4]
+            .into_iter()
+            .map(Interval::point)
+            .collect(),
+        2,
+        2,
+        Row,
+    );
+    let b = Matrix::from_vec(vec![x; 4], 2, 2, Row);
+    let c = &a * &b;
+    let d = &a + &b;
+    let af = ml_matrix("0.1 0.2; 0.3 0.4");
+    for i in 0..2 {
+        for j in 0..2 {
+            let s = af.row(i).sum();
+            assert!(c[(i, j)].contains(s) && c[(i, j)].contains(2f64 * s));
+            assert!(d[(i, j)].contains(af[(i, j)] + 1.5));
+        }
+    }
+    assert_eq!(
+        a.clone() - a.clone(),
+        Matrix::from_vec(vec![Interval::default(); 4], 2, 2, Row)
+    );
+}
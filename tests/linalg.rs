@@ -113,3 +113,56 @@ fn test_solve() {
         assert!(eq_vec(&x, &d, 1e-6));
     }
 }
+
+#[test]
+fn test_lanczos() {
+    set_global_seed(42);
+    let n = 60;
+    let mut a = zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            let d = (i as f64 - j as f64).abs();
+            a[(i, j)] = 1f64 / (1f64 + d * d);
+        }
+        a[(i, i)] += (i * i) as f64 / 10f64;
+    }
+    let dense = eigen(&a, Jacobi).eigenvalue;
+
+    let (values, vectors) = lanczos(&a, n, 4, 48, 1e-10, LanczosWhich::LargestAlgebraic).unwrap();
+    assert_eq!((vectors.row, vectors.col), (n, 4));
+    for i in 0..4 {
+        assert!((values[i] - dense[i]).abs() < 1e-8);
+        let v = vectors.col(i);
+        assert!((v.norm(Norm::L2) - 1f64).abs() < 1e-10);
+        let residual = (&a * &v).sub_vec(&v.fmap(|t| t * values[i]));
+        assert!(residual.norm(Norm::L2) < 1e-6 * values[i].abs());
+    }
+
+    // Smallest end of a positive-definite operator (evenly spaced spectrum)
+    let mut b = a.clone();
+    for i in 0..n {
+        b[(i, i)] = 1f64 + 10f64 * i as f64;
+    }
+    let dense_b = eigen(&b, Jacobi).eigenvalue;
+    let (small, _) = lanczos(&b, n, 3, 55, 1e-8, LanczosWhich::SmallestAlgebraic).unwrap();
+    for i in 0..3 {
+        assert!((small[i] - dense_b[n - 1 - i]).abs() < 1e-8);
+    }
+
+    // Sparse operator gives the same result
+    let sp = peroxide::structure::sparse::SPMatrix::from_dense(&a);
+    let (sp_values, _) = lanczos(&sp, n, 4, 48, 1e-10, LanczosWhich::LargestAlgebraic).unwrap();
+    assert!(eq_vec(&sp_values, &values, 1e-8));
+
+    // Too few iterations for the requested pairs
+    let e = lanczos(&b, n, 3, 6, 1e-10, LanczosWhich::SmallestAlgebraic).unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<LanczosError>(),
+        Some(LanczosError::NotConverge(c, 3)) if *c < 3
+    ));
+
+    // Invariant subspace smaller than k
+    let e = lanczos(&eye(n), n, 2, 10, 1e-10, LanczosWhich::LargestAlgebraic).unwrap_err();
+    assert_eq!(e.downcast_ref::<LanczosError>(), Some(&LanczosError::NotConverge(1, 2)));
+    clear_global_seed();
+}
@@ -111,3 +111,579 @@ fn test_kronecker() {
     let c1 = a1.kronecker(&b1);
     assert_eq!(c1, ml_matrix("0 5 0 10;6 7 12 14;0 15 0 20;18 21 24 28"));
 }
+
+#[test]
+fn test_fill_diagonal() {
+    let mut a = zeros(3, 3);
+    a.fill_diagonal(2f64);
+    assert_eq!(a, 2f64 * eye(3));
+
+    let mut b = zeros_shape(3, 3, Col);
+    b.set_diagonal(&[1f64, 2f64, 3f64]);
+    assert_eq!(b, ml_matrix("1 0 0;0 2 0;0 0 3"));
+}
+
+#[test]
+fn test_matrix_extremum() {
+    let a = matrix(c!(1, 4, 2, 9, 3, 5), 2, 3, Col);
+    assert_eq!(a.argmax(), (1, 1));
+    assert_eq!(a.max(), 9f64);
+    assert_eq!(a.argmin(), (0, 0));
+    assert_eq!(a.min(), 1f64);
+
+    let v = c!(2, 8, 1, 8);
+    assert_eq!(v.argmax(), 1);
+    assert_eq!(v.argmin(), 2);
+}
+
+#[test]
+fn test_generic_matrix() {
+    let a: Matrix<i64> = Matrix::from_vec(vec![1, 2, 3, 4], 2, 2, Row);
+    let b: Matrix<i64> = Matrix::from_vec(vec![4, 2, 3, 1], 2, 2, Col);
+    assert_eq!(a[(1, 0)], 3);
+    assert_eq!(b[(1, 0)], 2);
+
+    assert_eq!(&a + &b, Matrix::from_vec(vec![5, 5, 5, 5], 2, 2, Row));
+    assert_eq!(&a - &b, Matrix::from_vec(vec![-3, -1, 1, 3], 2, 2, Row));
+    assert_eq!(a.clone() * 2, Matrix::from_vec(vec![2, 4, 6, 8], 2, 2, Row));
+    assert_eq!(&a * &b, Matrix::from_vec(vec![8, 5, 20, 13], 2, 2, Row));
+    assert_eq!(a.transpose(), Matrix::from_vec(vec![1, 3, 2, 4], 2, 2, Row));
+
+    let c: Matrix<f32> = Matrix::from_vec(vec![0.5, 1.5], 1, 2, Row);
+    assert_eq!(c.clone() + c, Matrix::from_vec(vec![1f32, 3f32], 1, 2, Row));
+}
+
+#[test]
+fn test_matrix_bytes() {
+    let a = matrix(c!(1.5, -2, 3e10, 4, 5, 6), 2, 3, Col);
+    let b = Matrix::from_bytes(&a.to_bytes()).unwrap();
+    assert_eq!(b.shape, Col);
+    assert_eq!((b.row, b.col), (2, 3));
+    assert_eq!(a.data, b.data);
+
+    let bytes = a.to_bytes();
+    assert!(Matrix::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Matrix::from_bytes(&bytes[..10]).is_err());
+
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert!(Matrix::from_bytes(&corrupted).is_err());
+
+    // Usable as a standard error
+    let err: Box<dyn std::error::Error> = Box::new(MatrixBytesError::InvalidMagic);
+    assert_eq!(err.to_string(), "Invalid magic number for matrix binary format");
+}
+
+#[test]
+fn test_scale_mut() {
+    let mut a = eye_shape(3, Col);
+    a.scale_mut(2f64);
+    assert_eq!(a.shape, Col);
+    assert_eq!((a.row, a.col), (3, 3));
+    assert_eq!(a.diag(), c!(2, 2, 2));
+    assert_eq!(a, 2f64 * eye(3));
+
+    let b = ml_matrix("1 2;3 4");
+    assert_eq!(b.scale(3f64), 3f64 * b.clone());
+    assert_eq!(b.add_scalar(1f64), b.clone() + 1f64);
+}
+
+#[test]
+fn test_flatten_reshape() {
+    let a = matrix(c!(1, 2, 3, 4, 5, 6), 2, 3, Col);
+    let v = a.flatten(Row);
+    assert_eq!(v, c!(1, 3, 5, 2, 4, 6));
+    let b = matrix(v, 2, 3, Row);
+    assert_eq!(b.reshape_ordered(2, 3, Row), a);
+    assert_eq!(a.reshape_ordered(3, 2, Col), matrix(c!(1, 2, 3, 4, 5, 6), 3, 2, Col));
+}
+
+#[test]
+#[should_panic(expected = "6 elements")]
+fn test_reshape_mismatch() {
+    let a = zeros(2, 3);
+    a.reshape_ordered(4, 2, Row);
+}
+
+#[test]
+fn test_swap_rows_cols() {
+    let a = ml_matrix("1 2;3 4");
+    let mut b = a.clone();
+    b.swap_rows(0, 1);
+    assert_eq!(b, ml_matrix("3 4;1 2"));
+    b.swap_rows(0, 1);
+    assert_eq!(b, a);
+
+    let mut c = matrix(vec![1f64, 2f64, 3f64, 4f64, 5f64, 6f64], 2, 3, Col);
+    c.swap_cols(0, 2);
+    assert_eq!(c, matrix(vec![5f64, 6f64, 3f64, 4f64, 1f64, 2f64], 2, 3, Col));
+    c.swap_rows(1, 1);
+    assert_eq!(c, matrix(vec![5f64, 6f64, 3f64, 4f64, 1f64, 2f64], 2, 3, Col));
+}
+
+#[test]
+#[should_panic(expected = "Row index 2 is out of range (rows: 2)")]
+fn test_swap_rows_out_of_range() {
+    let mut a = ml_matrix("1 2;3 4");
+    a.swap_rows(0, 2);
+}
+
+#[test]
+fn test_gram_add_outer() {
+    let x = rand(7, 4);
+    assert!(gram(&x).data.iter().zip((&x.t() * &x).data.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    let y = x.change_shape();
+    assert!(gram(&y).data.iter().zip((&x.t() * &x).data.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+    let mut g = zeros(4, 4);
+    for i in 0..x.row {
+        let r = x.row(i);
+        g.add_outer(&r, &r);
+    }
+    assert!(g.data.iter().zip(gram(&x).data.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+    // Empty data
+    assert_eq!(gram(&zeros(0, 3)), zeros(3, 3));
+    assert_eq!((gram(&zeros(3, 0)).row, gram(&zeros(3, 0)).col), (0, 0));
+}
+
+#[test]
+fn test_from_fn() {
+    let v = Matrix::from_fn(3, 4, |i, j| (i as f64).powi(j as i32));
+    assert_eq!(v.shape, Row);
+    assert_eq!(v.row(2), vec![1f64, 2f64, 4f64, 8f64]);
+
+    let e: Matrix = Matrix::from_fn(0, 5, |_, _| panic!("should not be called"));
+    assert_eq!((e.row, e.col), (0, 5));
+    assert!(e.data.is_empty());
+
+    let n = Matrix::from_fn(2, 2, |i, j| i * 2 + j);
+    assert_eq!(n.data, vec![0usize, 1, 2, 3]);
+}
+
+#[test]
+fn test_matrix_pow() {
+    let r = ml_matrix("0 -1;1 0");
+    let r4 = r.pow(4);
+    assert!(r4.data.iter().zip(eye(2).data.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    assert_eq!(r.pow(1), r);
+    assert_eq!(r.pow(2), -eye(2));
+
+    let c = r.change_shape();
+    assert_eq!(c.pow(3).shape, Col);
+    assert_eq!(c.pow(3), r.pow(3));
+    assert_eq!(c.pow(0), eye(2));
+}
+
+#[test]
+#[should_panic(expected = "square")]
+fn test_matrix_pow_non_square() {
+    ml_matrix("1 2 3;4 5 6").pow(2);
+}
+
+#[test]
+fn test_diagonalize() {
+    let a = ml_matrix("4 1 0;1 3 1;0 1 2");
+    let (p, d) = a.diagonalize().unwrap();
+    let b = &p * &d * p.inv();
+    assert!(a.data.iter().zip(b.data.iter()).all(|(x, y)| (x - y).abs() < 1e-10));
+    assert!(d.data.iter().enumerate().all(|(k, x)| k % 4 == 0 || *x == 0f64));
+
+    assert!(ml_matrix("1 1;0 1").diagonalize().is_none());
+    assert!(ml_matrix("1 2 3;4 5 6").diagonalize().is_none());
+
+    // Non-symmetric with distinct eigenvalues
+    let b = ml_matrix("1 1;0 2");
+    let (p, d) = b.diagonalize().unwrap();
+    assert!(eq_vec(&d.diag(), &vec![2f64, 1f64], 1e-12));
+    assert!(eq_vec(&(&p * &d * p.inv()).data, &b.data, 1e-12));
+
+    // Non-symmetric with a repeated (semisimple) eigenvalue: S diag(3, 2, 2) S^{-1}
+    let s = ml_matrix("1 2 0;0 1 1;1 0 1");
+    let c = &s * &ml_matrix("3 0 0;0 2 0;0 0 2") * s.inv();
+    let (p, d) = c.diagonalize().unwrap();
+    assert!(eq_vec(&d.diag(), &vec![3f64, 2f64, 2f64], 1e-10));
+    assert!(eq_vec(&(&p * &d * p.inv()).data, &c.data, 1e-10));
+
+    // Defective (Jordan block) & complex eigenvalues
+    let j = &s * &ml_matrix("3 0 0;0 2 1;0 0 2") * s.inv();
+    assert!(j.diagonalize().is_none());
+    assert!(ml_matrix("0 -1;1 0").diagonalize().is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_matrix_json() {
+    let a = eye(3);
+    let b = Matrix::from_json(&a.to_json()).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.shape, b.shape);
+
+    let c = rand(3, 4).change_shape();
+    let d = Matrix::from_json(&c.to_json()).unwrap();
+    assert_eq!(c.data, d.data);
+    assert_eq!((c.row, c.col, c.shape), (d.row, d.col, d.shape));
+
+    assert!(Matrix::from_json(r#"{"data":[1.0],"row":2,"col":2,"shape":"Row"}"#).is_err());
+
+    // Non-finite values
+    let e = matrix(vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.5], 2, 2, Col);
+    let s = e.to_json();
+    assert!(s.contains(r#""NaN""#) && s.contains(r#""inf""#) && s.contains(r#""-inf""#));
+    let f = Matrix::from_json(&s).unwrap();
+    assert!(f[(0, 0)].is_nan());
+    assert_eq!(f.data[1..], e.data[1..]);
+    assert!(Matrix::from_json(r#"{"data":["x"],"row":1,"col":1,"shape":"Row"}"#).is_err());
+}
+
+#[test]
+fn test_companion() {
+    // 2(x - 1)(x + 2)(x - 3) = 2x^3 - 4x^2 - 10x + 12
+    let coeffs = [2f64, -4f64, -10f64, 12f64];
+    let c = Matrix::companion(&coeffs);
+    let p = poly(coeffs.to_vec());
+    for &lambda in &[-1.5, 0.0, 0.5, 2.0, 4.0] {
+        let char_poly = (&c - &(lambda * eye(3))).det();
+        // det(C - λI) = (-1)^n p(λ) / a_0
+        assert!((char_poly + p.eval(lambda) / 2f64).abs() < 1e-10);
+    }
+    for &root in &[1f64, -2f64, 3f64] {
+        assert!((&c - &(root * eye(3))).det().abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_cum_ops() {
+    let x = vec![2f64, -1f64, 3f64, 0.5];
+    assert_eq!(x.cumsum(), vec![2f64, 1f64, 4f64, 4.5]);
+    assert_eq!(x.cumprod(), vec![2f64, -2f64, -6f64, -3f64]);
+    assert_eq!(x.diff(), vec![-3f64, 4f64, -2.5]);
+    assert_eq!(x.cumsum().diff(), x[1..].to_vec());
+
+    let empty: Vec<f64> = vec![];
+    assert!(empty.cumsum().is_empty());
+    assert!(empty.cumprod().is_empty());
+    assert!(empty.diff().is_empty());
+    assert!(vec![1f64].diff().is_empty());
+}
+
+#[test]
+fn test_map_zip_map() {
+    let a = ml_matrix("1 -2;3 4");
+    assert_eq!(a.map(|x| x * x), ml_matrix("1 4;9 16"));
+    assert_eq!(a.map(|x| x * x).shape, a.shape);
+
+    let b = ml_matrix("10 20;30 40").change_shape();
+    let c = a.zip_map(&b, |x, y| x + y);
+    assert_eq!(c, ml_matrix("11 18;33 44"));
+    assert_eq!(c.shape, Row);
+
+    // `FPMatrix::zip_with` keeps its own argument order
+    assert_eq!(a.zip_with(|x, y| x + y, &a), 2f64 * a.clone());
+}
+
+#[test]
+#[should_panic(expected = "2x2 vs 2x3")]
+fn test_zip_map_mismatch() {
+    ml_matrix("1 2;3 4").zip_map(&ml_matrix("1 2 3;4 5 6"), |x, y| x + y);
+}
+
+#[test]
+fn test_solve_min_norm() {
+    // rank 2 (third row = first + second), null space spanned by (1, -2, 1)
+    let a = ml_matrix("1 2 3;4 5 6;5 7 9");
+    let x0 = c!(1, 1, 1);
+    let b = &a * &x0;
+    let x = a.solve_min_norm(&b);
+
+    let ax = &a * &x;
+    assert!(eq_vec(&ax, &b, 1e-10));
+    // minimum norm <=> orthogonal to the null space
+    let null = c!(1, -2, 1);
+    assert!(x.dot(&null).abs() < 1e-10);
+    for t in [-1f64, -0.1, 0.1, 1f64] {
+        let y = x.add_v(&null.mul_s(t));
+        assert!(eq_vec(&(&a * &y), &b, 1e-10));
+        assert!(y.norm(Norm::L2) > x.norm(Norm::L2));
+    }
+
+    // full rank: same as ordinary solve
+    let c = ml_matrix("2 1;1 3");
+    let y = c.solve_min_norm(&[3f64, 5f64]);
+    assert!(eq_vec(&y, &c.solve(&c!(3, 5), LU), 1e-12));
+
+    // underdetermined: x + y + z = 3
+    let d = ml_matrix("1 1 1");
+    assert!(eq_vec(&d.solve_min_norm(&[3f64]), &c!(1, 1, 1), 1e-12));
+
+    // zero matrix
+    assert_eq!(zeros(2, 2).solve_min_norm(&[0f64, 0f64]), c!(0, 0));
+}
+
+#[test]
+fn test_batched_matmul() {
+    let mut rng = stdrng_from_seed(42);
+    let w = rand_with_rng(4, 3, &mut rng);
+    let batch: Vec<Matrix> = (0..10)
+        .map(|i| {
+            let m = rand_with_rng(5, 4, &mut rng);
+            if i % 2 == 0 { m } else { m.change_shape() }
+        })
+        .collect();
+    let out = batched_matmul(&batch, &w);
+    assert_eq!(out.len(), batch.len());
+    for (m, o) in batch.iter().zip(out.iter()) {
+        let expected = m * &w;
+        assert_eq!((o.row, o.col), (5, 3));
+        assert!(eq_vec(&o.flatten(Row), &expected.flatten(Row), 1e-12));
+    }
+    assert!(batched_matmul(&[], &w).is_empty());
+
+    // Zero-sized shapes
+    let empty = batched_matmul(&[zeros(0, 4), zeros(0, 4)], &w);
+    assert_eq!(empty.len(), 2);
+    assert!(empty.iter().all(|o| (o.row, o.col) == (0, 3) && o.data.is_empty()));
+    let no_col = batched_matmul(&[rand(5, 4)], &zeros(4, 0));
+    assert_eq!((no_col[0].row, no_col[0].col), (5, 0));
+    let no_inner = batched_matmul(&[zeros(5, 0)], &zeros(0, 3));
+    assert_eq!(no_inner[0], zeros(5, 3));
+}
+
+#[test]
+fn test_matrix_concat() {
+    let a = ml_matrix("1 2;3 4");
+    let b = ml_matrix("5 6;7 8").change_shape();
+    let c = ml_matrix("9 10;11 12");
+
+    let r = Matrix::concat(&[a.clone(), b.clone(), c.clone()], Axis::Row).unwrap();
+    let r_chain = rbind(rbind(a.clone(), b.clone()).unwrap(), c.clone()).unwrap();
+    assert_eq!(r, r_chain);
+    assert_eq!((r.row, r.col), (6, 2));
+
+    let cc = Matrix::concat(&[a.clone(), b.clone(), c.clone()], Axis::Col).unwrap();
+    let c_chain = cbind(cbind(a.clone(), b.clone()).unwrap(), c.clone()).unwrap();
+    assert_eq!(cc, c_chain);
+    assert_eq!((cc.row, cc.col), (2, 6));
+
+    assert!(Matrix::concat(&[a.clone(), ml_matrix("1 2 3")], Axis::Row).is_err());
+    assert!(Matrix::concat(&[a, ml_matrix("1 2 3")], Axis::Col).is_err());
+    assert!(Matrix::concat(&[], Axis::Row).is_err());
+}
+
+#[test]
+fn test_matrix_split() {
+    let a = py_matrix(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ]);
+    for m in [a.clone(), a.change_shape()] {
+        let rows = m.split(Axis::Row, &[2, 0, 1]);
+        assert_eq!(rows.len(), 3);
+        assert_eq!((rows[1].row, rows[1].col), (0, 4));
+        assert_eq!(Matrix::concat(&rows, Axis::Row).unwrap(), a);
+
+        let cols = m.split(Axis::Col, &[1, 3]);
+        assert_eq!(Matrix::concat(&cols, Axis::Col).unwrap(), a);
+
+        let even = m.split_even(Axis::Col, 2);
+        assert_eq!(even[1], ml_matrix("3 4;7 8;11 12"));
+        assert_eq!(Matrix::concat(&even, Axis::Col).unwrap(), a);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_split_bad_sizes() {
+    ml_matrix("1 2;3 4").split(Axis::Row, &[1, 2]);
+}
+
+#[test]
+fn test_sum_axis_accurate() {
+    // Each row: one huge value, many small ones, then cancel the huge value
+    let n = 1000;
+    let mut rows = vec![];
+    for k in 0..3 {
+        let big = 10f64.powi(14 + k);
+        let mut row = vec![big];
+        row.extend(vec![0.1; n]);
+        row.push(-big);
+        rows.push(row);
+    }
+    let a = py_matrix(rows);
+    let exact = 0.1 * n as f64;
+
+    let naive = a.sum_axis(Axis::Col);
+    let accurate = a.sum_axis_accurate(Axis::Col);
+    for (x, y) in naive.iter().zip(accurate.iter()) {
+        assert!((y - exact).abs() < 1e-9);
+        assert!((x - exact).abs() > 1e-3);
+    }
+
+    let at = a.t();
+    assert_eq!(at.sum_axis_accurate(Axis::Row), accurate);
+    assert_eq!(ml_matrix("1 2;3 4").sum_axis_accurate(Axis::Row), vec![4f64, 6f64]);
+}
+
+#[test]
+fn test_max_abs_diff() {
+    let a = ml_matrix("1 2 3;4 5 6;7 8 9");
+    let mut b = a.change_shape();
+    b[(2, 1)] += 3e-7;
+    b[(0, 2)] -= 1e-9;
+
+    let (d, pos) = a.max_abs_diff(&b);
+    assert!((d - 3e-7).abs() < 1e-12);
+    assert_eq!(pos, (2, 1));
+
+    let diff = a.diff_matrix(&b);
+    assert_eq!(diff.shape, a.shape);
+    assert!((diff[(2, 1)] + 3e-7).abs() < 1e-12);
+    assert!((diff[(0, 2)] - 1e-9).abs() < 1e-15);
+    assert_eq!(diff[(1, 1)], 0f64);
+
+    assert_eq!(a.max_abs_diff(&a), (0f64, (0, 0)));
+    b[(1, 0)] = f64::NAN;
+    let (d, pos) = a.max_abs_diff(&b);
+    assert!(d.is_nan());
+    assert_eq!(pos, (1, 0));
+}
+
+#[test]
+#[should_panic]
+fn test_diff_matrix_mismatch() {
+    ml_matrix("1 2").diff_matrix(&ml_matrix("1;2"));
+}
+
+#[test]
+fn test_frobenius_dot() {
+    let a = ml_matrix("1 -2 3;4 5 -6");
+    let f = a.norm(Norm::F);
+    assert!((a.frobenius_dot(&a) - f * f).abs() < 1e-12);
+
+    // Row-major vs column-major storage
+    let b = ml_matrix("1 1 1;2 2 2").change_shape();
+    assert_eq!(a.frobenius_dot(&b), 2f64 + 6f64);
+    assert_eq!(a.frobenius_dot(&b), b.frobenius_dot(&a));
+
+    assert!(a.angle_between(&a).abs() < 1e-7);
+    assert!((a.angle_between(&(-1f64 * &a)) - std::f64::consts::PI).abs() < 1e-7);
+    assert!(a.angle_between(&zeros(2, 3)).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_frobenius_dot_mismatch() {
+    ml_matrix("1 2").frobenius_dot(&ml_matrix("1;2"));
+}
+
+#[test]
+fn test_iter_mut_indexed() {
+    let lower = ml_matrix("1 0 0;4 5 0;7 8 9");
+    let a = ml_matrix("1 2 3;4 5 6;7 8 9");
+    for mut a in [a.clone(), a.change_shape()] {
+        let shape = a.shape;
+        let mut visited = vec![];
+        for ((i, j), x) in a.iter_mut_indexed() {
+            visited.push((i, j));
+            if j > i {
+                *x = 0f64;
+            }
+        }
+        assert_eq!(a, lower);
+        assert_eq!(a.shape, shape);
+        match shape {
+            Row => assert_eq!(&visited[..3], &[(0, 0), (0, 1), (0, 2)]),
+            Col => assert_eq!(&visited[..3], &[(0, 0), (1, 0), (2, 0)]),
+        }
+    }
+}
+
+#[test]
+fn test_diag_solve() {
+    let d = ml_matrix("2 0 0;0 -4 0;0 0 0.5");
+    assert!(d.is_diagonal());
+    assert!(!ml_matrix("2 0;1e-300 1").is_diagonal());
+
+    let b = vec![1f64, 2f64, 3f64];
+    let x = d.diag_solve(&b).unwrap();
+    assert_eq!(x, vec![0.5, -0.5, 6f64]);
+    assert!(eq_vec(&x, &d.solve_min_norm(&b), 1e-12));
+    assert_eq!(d.solve(&b, LU), x);
+    assert_eq!(d.solve(&b, WAZ), x);
+
+    let m = ml_matrix("1 2;3 4;5 6");
+    let y = d.solve_mat(&m, LU);
+    assert_eq!(y.col(0), d.diag_solve(&m.col(0)).unwrap());
+    assert_eq!(y.col(1), d.diag_solve(&m.col(1)).unwrap());
+
+    assert!(ml_matrix("1 1;0 1").diag_solve(&[1f64, 1f64]).is_err());
+    let err = d.diag_solve(&[1f64]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<DiagSolveError>(),
+        Some(&DiagSolveError::LengthMismatch(3, 1))
+    );
+    let err: Box<dyn Error> = Box::new(DiagSolveError::NotDiagonal);
+    assert_eq!(err.to_string(), "Matrix is not diagonal");
+}
+
+#[test]
+#[should_panic(expected = "zero diagonal at 1")]
+fn test_solve_uses_diag_solve() {
+    ml_matrix("1 0;0 0").solve(&vec![1f64, 1f64], LU);
+}
+
+#[test]
+fn test_power_iteration() {
+    let a = ml_matrix("10 2 1;2 5 1;1 1 2");
+    let (lambda, v) = a.power_iteration(1e-10, 1000);
+    let eigenvalues = eigen(&a, EigenMethod::Jacobi).eigenvalue;
+    let dominant = eigenvalues.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    assert!((lambda - dominant).abs() < 1e-9);
+    assert!((v.norm(Norm::L2) - 1f64).abs() < 1e-12);
+    assert!(eq_vec(&(&a * &v), &v.mul_scalar(lambda), 1e-9));
+
+    // Negative dominant eigenvalue
+    let (lambda, _) = ml_matrix("-5 0;0 1").power_iteration(1e-10, 1000);
+    assert!((lambda + 5f64).abs() < 1e-10);
+
+    // I + 10 w w^T / |w|^2 : eigenvalues 11 (along w) & 1,
+    // the former fixed start 1 + i/n was orthogonal to w = (1.5, -1)
+    let w = ml_matrix("1.5;-1");
+    let b = eye(2) + (&w * &w.t()) * (10f64 / 3.25);
+    for _ in 0..10 {
+        let (lambda, v) = b.power_iteration(1e-10, 1000);
+        assert!((lambda - 11f64).abs() < 1e-9);
+        assert!(eq_vec(&(&b * &v), &v.mul_scalar(11f64), 1e-8));
+    }
+
+    // Nearest to the shift
+    for &target in eigenvalues.iter() {
+        let (mu, w) = a.inverse_power_iteration(target + 0.1, 1e-10, 1000);
+        assert!((mu - target).abs() < 1e-9);
+        assert!(eq_vec(&(&a * &w), &w.mul_scalar(mu), 1e-9));
+    }
+}
+
+#[test]
+fn test_slogdet() {
+    // det = (1e10)^100 * 2^50 overflows, log-determinant does not
+    let n = 100;
+    let mut d = zeros(n, n);
+    for i in 0..n {
+        d[(i, i)] = if i % 2 == 0 { 1e10 } else { -2e10 };
+    }
+    assert!(d.det().is_infinite());
+    let (sgn, logdet) = d.slogdet();
+    let expected = 100f64 * 1e10f64.ln() + 50f64 * 2f64.ln();
+    assert_eq!(sgn, 1f64);
+    assert!((logdet - expected).abs() < 1e-9 * expected);
+
+    // Agrees with det for a small pivoted matrix
+    let a = ml_matrix("0 2 1;1 1 0;3 0 1");
+    let (sgn, logdet) = a.slogdet();
+    assert!((sgn * logdet.exp() - a.det()).abs() < 1e-12);
+
+    let s = ml_matrix("1 2;2 4");
+    assert_eq!(s.slogdet(), (0f64, f64::NEG_INFINITY));
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "mmap")]
+extern crate peroxide;
+use peroxide::fuga::*;
+use std::fs;
+
+#[test]
+fn test_mmap_matrix() {
+    let a = matrix((0..60).map(|x| x as f64 * 0.5).collect::<Vec<f64>>(), 6, 10, Col);
+    let path = std::env::temp_dir().join("peroxide_test_mmap.bin");
+    let path = path.to_str().unwrap();
+    fs::write(path, a.to_bytes()).unwrap();
+
+    let m = MmapMatrix::open(path).unwrap();
+    assert_eq!((m.row, m.col, m.shape), (6, 10, Col));
+    for &(i, j) in [(0, 0), (5, 9), (3, 7), (2, 1)].iter() {
+        assert_eq!(m.at(i, j), a[(i, j)]);
+    }
+    assert_eq!(m.row(4), a.row(4));
+    assert_eq!(m.col(8), a.col(8));
+    assert_eq!(m.to_matrix(), a);
+
+    drop(m);
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,45 @@
+//! Core subset which does not need the `std` feature
+//!
+//! Run with `cargo test --no-default-features --test no_std`
+use peroxide::statistics::stat::Statistics;
+use peroxide::structure::matrix::{matrix, Col, LinearAlgebra, Matrix, Row};
+use peroxide::traits::fp::FPMatrix;
+use peroxide::traits::math::{InnerProduct, Norm, Normed};
+use peroxide::util::non_macro::{eye, zeros};
+
+#[test]
+fn test_no_std_matrix_arithmetic() {
+    let a = matrix(vec![1f64, 2f64, 3f64, 4f64], 2, 2, Row);
+    let b = matrix(vec![1f64, 3f64, 2f64, 4f64], 2, 2, Col);
+    assert_eq!(a, b);
+
+    assert_eq!(&a + &b, 2f64 * a.clone());
+    assert_eq!(&a - &b, zeros(2, 2));
+    assert_eq!(&a * &eye(2), a);
+    assert_eq!(&a * &a, matrix(vec![7f64, 10f64, 15f64, 22f64], 2, 2, Row));
+    assert_eq!(a.t(), matrix(vec![1f64, 3f64, 2f64, 4f64], 2, 2, Row));
+    assert_eq!(&a * &vec![1f64, 1f64], vec![3f64, 7f64]);
+
+    assert_eq!(a.det(), -2f64);
+    let i = &a * &a.inv();
+    assert!(i
+        .data
+        .iter()
+        .zip(eye(2).data.iter())
+        .all(|(x, y)| (x - y).abs() < 1e-12));
+
+    assert_eq!(a.norm(Norm::F), 30f64.sqrt());
+    assert_eq!(a.dot(&b), 30f64);
+    assert_eq!(a.fmap(|x| x * x).data, vec![1f64, 4f64, 9f64, 16f64]);
+}
+
+#[test]
+fn test_no_std_stat() {
+    let v = vec![1f64, 2f64, 3f64, 4f64];
+    assert_eq!(v.mean(), 2.5);
+    assert_eq!(v.var(), 5f64 / 3f64);
+    assert_eq!(v.norm(Norm::L1), 10f64);
+
+    let m: Matrix = matrix(vec![1f64, 2f64, 3f64, 4f64], 2, 2, Col);
+    assert_eq!(m.mean(), vec![1.5, 3.5]);
+}
@@ -68,3 +68,175 @@ fn test_cubic_spline_extension() -> Result<(), Box<dyn std::error::Error>>{
 
     Ok(())
 }
+
+#[test]
+fn test_richardson() {
+    let x0 = 0.5f64;
+    let forward = |h: f64| ((x0 + h).exp() - x0.exp()) / h;
+    let naive = forward(1e-3);
+    let accel = richardson(forward, 0.1, 6, 1f64);
+    assert!((naive - x0.exp()).abs() > 1e-4);
+    assert!((accel - x0.exp()).abs() < 1e-9);
+
+    let central = |h: f64| ((x0 + h).ln() - (x0 - h).ln()) / (2f64 * h);
+    let accel = richardson(central, 0.1, 5, 2f64);
+    assert!((accel - 1f64 / x0).abs() < 1e-11);
+
+    assert_eq!(richardson(|h| h, 0.1, 1, 1f64), 0.1);
+}
+
+#[test]
+fn test_laplacian_2d() {
+    let (nx, ny) = (8, 6);
+    let (dx, dy) = (0.1, 0.2);
+    let l = laplacian_2d(nx, ny, dx, dy);
+    assert_eq!((l.row, l.col), (nx * ny, nx * ny));
+    assert_eq!(l, l.t());
+
+    // u = x^2 + y^2 on interior points
+    let mut u = vec![0f64; nx * ny];
+    for j in 0..ny {
+        for i in 0..nx {
+            let (x, y) = ((i + 1) as f64 * dx, (j + 1) as f64 * dy);
+            u[i + nx * j] = x.powi(2) + y.powi(2);
+        }
+    }
+    let lu = &l * &u;
+    for j in 1..ny - 1 {
+        for i in 1..nx - 1 {
+            assert!((lu[i + nx * j] - 4f64).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_arc_length_curvature() {
+    let r = 2.5;
+    let n = 2001;
+    let t = linspace(0, 2f64 * std::f64::consts::PI, n);
+    let x = t.fmap(|t| r * t.cos());
+    let y = t.fmap(|t| r * t.sin());
+
+    let l = arc_length(&x, &y);
+    assert!((l - 2f64 * std::f64::consts::PI * r).abs() < 1e-5);
+
+    let k = curvature(&x, &y);
+    assert_eq!(k.len(), n);
+    for ki in k {
+        assert!((ki - 1f64 / r).abs() < 1e-5);
+    }
+
+    // Clockwise: negative curvature
+    let y_cw = y.fmap(|t| -t);
+    assert!(curvature(&x, &y_cw)
+        .iter()
+        .all(|&ki| (ki + 1f64 / r).abs() < 1e-5));
+}
+
+struct Oscillator;
+
+impl ODEProblem for Oscillator {
+    fn initial_conditions(&self) -> Vec<f64> {
+        vec![1f64, 0f64]
+    }
+
+    fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> anyhow::Result<()> {
+        dy[0] = y[1];
+        dy[1] = -y[0];
+        Ok(())
+    }
+}
+
+#[test]
+fn test_ode_checkpoint_resume() -> anyhow::Result<()> {
+    let solver = BasicODESolver::new(RKF45::new(1e-6, 0.9, 1e-6, 1e-1, 100));
+    let (t_full, y_full) = solver.solve(&Oscillator, (0f64, 10f64), 0.01)?;
+
+    // Integrate halfway, checkpoint, then resume
+    let init = ODEState {
+        t: 0f64,
+        y: Oscillator.initial_conditions(),
+        dt: 0.01,
+    };
+    let (t_first, y_first, state) = solver.solve_from(&Oscillator, init, 5f64)?;
+    let buffer = state.checkpoint();
+    assert_eq!(ODEState::from_checkpoint(&buffer)?, state);
+
+    let (t_rest, y_rest, _) = solver.resume(&buffer, &Oscillator, 10f64)?;
+    assert_eq!(t_rest[0], *t_first.last().unwrap());
+
+    let t_joined = [t_first, t_rest[1..].to_vec()].concat();
+    let y_joined = [y_first, y_rest[1..].to_vec()].concat();
+    assert_eq!(t_joined, t_full);
+    assert_eq!(y_joined, y_full);
+
+    // Corrupted buffers
+    let err = |b: &[u8]| {
+        ODEState::from_checkpoint(b)
+            .unwrap_err()
+            .downcast::<ODECheckpointError>()
+            .unwrap()
+    };
+    assert_eq!(err(&buffer[..buffer.len() - 1]), ODECheckpointError::LengthMismatch(16, 15));
+    assert_eq!(err(b"PRXO"), ODECheckpointError::TooShort);
+    let mut corrupted = buffer.clone();
+    corrupted[0] = b'X';
+    assert_eq!(err(&corrupted), ODECheckpointError::InvalidMagic);
+    Ok(())
+}
+
+/// Fixed-step Euler solver which only implements `solve`
+struct Euler;
+
+impl ODESolver for Euler {
+    fn solve<P: ODEProblem>(&self, problem: &P, t_span: (f64, f64), dt: f64) -> anyhow::Result<(Vec<f64>, Vec<Vec<f64>>)> {
+        let mut t = t_span.0;
+        let mut y = problem.initial_conditions();
+        let mut dy = vec![0f64; y.len()];
+        let (mut t_vec, mut y_vec) = (vec![t], vec![y.clone()]);
+        while t < t_span.1 {
+            problem.rhs(t, &y, &mut dy)?;
+            y.iter_mut().zip(dy.iter()).for_each(|(yi, di)| *yi += di * dt);
+            t += dt;
+            t_vec.push(t);
+            y_vec.push(y.clone());
+        }
+        Ok((t_vec, y_vec))
+    }
+}
+
+#[test]
+fn test_ode_resume_default() -> anyhow::Result<()> {
+    let (_, y_full) = Euler.solve(&Oscillator, (0f64, 2f64), 0.125)?;
+
+    let init = ODEState {
+        t: 0f64,
+        y: Oscillator.initial_conditions(),
+        dt: 0.125,
+    };
+    let (_, _, state) = Euler.solve_from(&Oscillator, init, 1f64)?;
+    assert_eq!(state.t, 1f64);
+    assert_eq!(state.dt, 0.125);
+
+    let (t_rest, y_rest, _) = Euler.resume(&state.checkpoint(), &Oscillator, 2f64)?;
+    assert_eq!(t_rest, linspace(1, 2, 9));
+    assert_eq!(y_rest.last(), y_full.last());
+    Ok(())
+}
+
+#[test]
+fn test_tabulate_spline() -> anyhow::Result<()> {
+    let (x, y) = tabulate(f64::sin, 0, std::f64::consts::PI, 21);
+    assert_eq!(x, linspace(0, std::f64::consts::PI, 21));
+    assert_eq!(y, x.fmap(f64::sin));
+
+    let s = cubic_spline(&x, &y)?;
+    for t in linspace(0.05, 3.05, 31) {
+        assert!((s.eval(t) - t.sin()).abs() < 1e-4);
+    }
+
+    let (gx, gy, z) = tabulate_2d(|a, b| a + 10f64 * b, (0f64, 1f64, 3), (0f64, 2f64, 2));
+    assert_eq!((z.row, z.col), (gx.len(), gy.len()));
+    assert_eq!(z.row(2), vec![1f64, 21f64]);
+    Ok(())
+}
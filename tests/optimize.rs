@@ -49,4 +49,103 @@ fn f(x: &Vec<f64>, p: Vec<AD>) -> Option<Vec<AD>> {
             .map(|t| p[0] * t.powi(2) + p[1] * t + p[2])
             .collect()
     )
-}
\ No newline at end of file
+}
+#[test]
+fn test_optimize_callback() {
+    let x = seq(0, 10, 0.1);
+    let y = x.fmap(|t| t.powi(2) + 2f64 * t + 3f64);
+    let data = hstack!(x, y);
+
+    let mut calls = vec![];
+    let mut on_iteration = |i: usize, chi2: f64| calls.push((i, chi2));
+
+    let mut opt = Optimizer::new(data, f);
+    opt.set_init_param(vec![1f64, 1f64, 1f64])
+        .set_max_iter(100)
+        .set_method(GradientDescent)
+        .set_lr(1e-6)
+        .optimize_with_callback(Some(&mut on_iteration));
+
+    assert_eq!(calls.len(), 100);
+    assert!(calls.iter().enumerate().all(|(k, (i, _))| k == *i));
+    assert!(calls[99].1 < calls[0].1);
+}
+
+#[test]
+fn test_optimize_callback_skips_retries() {
+    // Model undefined for p > 1.5: GD reaches p = 1.385, then retries 3 times & stops
+    let x = seq(0, 10, 1);
+    let y = x.fmap(|t| 2f64 * t);
+    let data = hstack!(x, y);
+
+    let mut calls = vec![];
+    let mut on_iteration = |i: usize, chi2: f64| calls.push((i, chi2));
+
+    let mut opt = Optimizer::new(data, |x: &Vec<f64>, p: Vec<AD>| {
+        if p[0].x() > 1.5 {
+            None
+        } else {
+            Some(x.iter().map(|t| p[0] * *t).collect())
+        }
+    });
+    let p = opt
+        .set_init_param(vec![1f64])
+        .set_max_iter(100)
+        .set_method(GradientDescent)
+        .set_lr(1e-3)
+        .optimize_with_callback(Some(&mut on_iteration));
+
+    assert!((p[0] - 1.385).abs() < 1e-12);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, 0);
+    assert!((calls[0].1 - 385f64 * 0.615f64.powi(2)).abs() < 1e-9);
+}
+
+#[test]
+fn test_optimize_time_budget() {
+    let x = seq(0, 10, 0.1);
+    let y = x.fmap(|t| t.powi(2) + 2f64 * t + 3f64);
+    let data = hstack!(x, y);
+
+    let budget = std::time::Duration::from_millis(1);
+    let mut opt = Optimizer::new(data, f);
+    let start = std::time::Instant::now();
+    let p = opt
+        .set_init_param(vec![1f64, 1f64, 1f64])
+        .set_max_iter(1_000_000)
+        .set_method(GradientDescent)
+        .set_lr(1e-6)
+        .set_time_budget(Some(budget))
+        .optimize();
+
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    assert_eq!(opt.get_status(), OptStatus::TimedOut);
+    assert_eq!(p.len(), 3);
+    assert!(p.iter().all(|x| x.is_finite()));
+
+    opt.set_max_iter(10).set_time_budget(None).optimize();
+    assert_eq!(opt.get_status(), OptStatus::Finished);
+}
+
+#[test]
+fn test_optimize_time_budget_returns_best() {
+    // Learning rate too large: every step increases the residual
+    let x = seq(0, 10, 1);
+    let y = x.fmap(|t| 2f64 * t);
+    let data = hstack!(x, y);
+
+    let mut opt = Optimizer::new(data, |x: &Vec<f64>, p: Vec<AD>| {
+        Some(x.iter().map(|t| p[0] * *t).collect())
+    });
+    let p = opt
+        .set_init_param(vec![1f64])
+        .set_max_iter(usize::MAX)
+        .set_method(GradientDescent)
+        .set_lr(1e-2)
+        .set_time_budget(Some(std::time::Duration::from_millis(10)))
+        .optimize();
+
+    assert_eq!(opt.get_status(), OptStatus::TimedOut);
+    assert_eq!(p, vec![1f64]);
+    assert!(opt.get_error().is_finite());
+}
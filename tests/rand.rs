@@ -0,0 +1,39 @@
+use peroxide::fuga::*;
+
+#[test]
+fn test_rand_from() {
+    let mut rng = stdrng_from_seed(1234);
+    let m = rand_from(200, 50, &Normal(2, 3), &mut rng);
+    assert_eq!((m.row, m.col), (200, 50));
+    let x = m.data.clone();
+    assert!((x.mean() - 2f64).abs() < 0.1);
+    assert!((x.var() - 9f64).abs() < 0.4);
+
+    let mut rng1 = stdrng_from_seed(7);
+    let mut rng2 = stdrng_from_seed(7);
+    assert_eq!(
+        rand_from(3, 3, &Uniform(0, 1), &mut rng1),
+        rand_from(3, 3, &Uniform(0, 1), &mut rng2)
+    );
+}
+
+#[test]
+fn test_set_global_seed() {
+    set_global_seed(2024);
+    let a = rand(2, 2);
+    let b = rand(2, 2);
+    set_global_seed(2024);
+    assert_eq!(rand(2, 2), a);
+    assert_eq!(rand(2, 2), b);
+
+    // same stream as an explicitly seeded rng => same result across runs
+    let mut rng = stdrng_from_seed(2024);
+    assert_eq!(rand_with_rng(2, 2, &mut rng), a);
+
+    // explicit-rng APIs bypass the global rng
+    set_global_seed(1);
+    let mut rng = stdrng_from_seed(2024);
+    assert_eq!(rand_with_rng(2, 2, &mut rng), a);
+
+    clear_global_seed();
+}
@@ -62,3 +62,115 @@ fn series_zip_with_test() {
     let s_c = Series::new(c);
     assert_eq!(s_c, s_a.zip_with(|x: i32, y: i32| x + y, &s_b));
 }
+
+#[test]
+fn dataframe_schema_test() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("x", Series::new(vec![1usize, 2, 3]));
+    df.push("y", Series::new(c!(0.1, 0.2, 0.3)));
+    df.push("z", Series::new(vec!['a', 'b', 'c']));
+    df.push("w", Series::new(vec![true, false, true]));
+
+    assert_eq!(
+        df.schema(),
+        vec![
+            ("x".to_string(), USIZE),
+            ("y".to_string(), F64),
+            ("z".to_string(), Char),
+            ("w".to_string(), Bool),
+        ]
+    );
+
+    let headers: Vec<&str> = df.iter_columns().map(|(h, _)| h).collect();
+    assert_eq!(headers, vec!["x", "y", "z", "w"]);
+    for (h, s) in df.iter_columns() {
+        assert_eq!(&df[h], s);
+    }
+}
+
+#[test]
+fn dataframe_sort_by_test() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("x", Series::new(c!(0.2, f64::NAN, 0.9, 0.1)));
+    df.push("y", Series::new(vec!['a', 'b', 'c', 'd']));
+
+    let desc = df.sort_by("x", false);
+    assert_eq!(desc["y"], Series::new(vec!['c', 'a', 'd', 'b']));
+    let x: Vec<f64> = desc["x"].to_vec();
+    assert_eq!(&x[..3], &[0.9, 0.2, 0.1]);
+    assert!(x[3].is_nan());
+
+    let asc = df.sort_by("x", true);
+    assert_eq!(asc["y"], Series::new(vec!['d', 'a', 'c', 'b']));
+
+    let by_char = desc.sort_by("y", true);
+    assert_eq!(by_char["y"], Series::new(vec!['a', 'b', 'c', 'd']));
+}
+
+#[test]
+fn dataframe_inner_join_test() {
+    let mut left = DataFrame::new(vec![]);
+    left.push("k", Series::new(vec!['a', 'b', 'a', 'c']));
+    left.push("v", Series::new(vec![1, 2, 3, 4]));
+
+    let mut right = DataFrame::new(vec![]);
+    right.push("key", Series::new(vec!['a', 'a', 'b', 'd']));
+    right.push("v", Series::new(vec![10.0, 20.0, 30.0, 40.0]));
+
+    let df = left.inner_join(&right, "k", "key");
+    assert_eq!(
+        df.header(),
+        &vec!["k".to_string(), "v".to_string(), "v_right".to_string()]
+    );
+    assert_eq!(df["k"], Series::new(vec!['a', 'a', 'b', 'a', 'a']));
+    assert_eq!(df["v"], Series::new(vec![1, 1, 2, 3, 3]));
+    assert_eq!(df["v_right"], Series::new(vec![10.0, 20.0, 30.0, 10.0, 20.0]));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn dataframe_json_test() {
+    let mut df = DataFrame::new(vec![]);
+    df.push("a", Series::new(vec![1usize, 2, 3]));
+    df.push("b", Series::new(vec![0.1, 1f64 / 3f64, -2.5e-300]));
+    df.push("c", Series::new(vec!["x".to_string(), "y".to_string(), "z".to_string()]));
+
+    let dg = DataFrame::from_json(&df.to_json()).unwrap();
+    assert_eq!(df, dg);
+
+    let bad = df.to_json().replace("\"dtype\":\"USIZE\"", "\"dtype\":\"F64\"");
+    assert_ne!(bad, df.to_json());
+    assert!(DataFrame::from_json(&bad).is_err());
+
+    // Non-finite values
+    let mut dh = DataFrame::new(vec![]);
+    dh.push("x", Series::new(vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.5]));
+    dh.push("y", Series::new(vec![f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 0.1f32]));
+    let di = DataFrame::from_json(&dh.to_json()).unwrap();
+    let x: &[f64] = di["x"].as_slice();
+    assert!(x[0].is_nan());
+    assert_eq!(x[1..], [f64::INFINITY, f64::NEG_INFINITY, 1.5]);
+    let y: &[f32] = di["y"].as_slice();
+    assert!(y[0].is_nan());
+    assert_eq!(y[1..], [f32::INFINITY, f32::NEG_INFINITY, 0.1f32]);
+}
+
+#[test]
+fn series_unique_value_counts_test() {
+    let a = Series::new(vec!['a', 'b', 'c', 'd', 'b']);
+    assert_eq!(a.unique(), Series::new(vec!['a', 'b', 'c', 'd']));
+
+    let vc = a.value_counts();
+    assert_eq!(vc.header(), &vec!["value".to_string(), "count".to_string()]);
+    assert_eq!(vc["value"], Series::new(vec!['b', 'a', 'c', 'd']));
+    assert_eq!(vc["count"], Series::new(vec![2usize, 1, 1, 1]));
+
+    let b = Series::new(vec![2.5, f64::NAN, 1.0, 2.5, f64::NAN, 3.0]);
+    assert_eq!(b.unique().len(), 4);
+    let vc = b.value_counts();
+    assert_eq!(vc["count"], Series::new(vec![2usize, 2, 1, 1]));
+    let values: Vec<f64> = vc["value"].to_vec();
+    assert_eq!(values[0], 2.5);
+    assert!(values[1].is_nan());
+    assert_eq!(&values[2..], &[1.0, 3.0]);
+}
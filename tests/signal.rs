@@ -0,0 +1,309 @@
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_levinson_durbin_ar2() {
+    // AR(2): x_t = 0.5 x_{t-1} - 0.3 x_{t-2} + e_t, Var(e) = 2
+    let (phi1, phi2, sigma2) = (0.5, -0.3, 2f64);
+    let rho1 = phi1 / (1f64 - phi2);
+    let rho2 = phi1 * rho1 + phi2;
+    let rho3 = phi1 * rho2 + phi2 * rho1;
+    let gamma0 = sigma2 / (1f64 - phi1 * rho1 - phi2 * rho2);
+    let r = vec![gamma0, gamma0 * rho1, gamma0 * rho2, gamma0 * rho3];
+
+    let (a, e) = levinson_durbin(&r, 2);
+    assert!(eq_vec(&a, &vec![phi1, phi2], 1e-12));
+    assert!((e - sigma2).abs() < 1e-12);
+
+    // Over-fitting gives zero for higher lag
+    let (a3, e3) = levinson_durbin(&r, 3);
+    assert!(eq_vec(&a3, &vec![phi1, phi2, 0f64], 1e-12));
+    assert!((e3 - sigma2).abs() < 1e-12);
+
+    // Same as dense Toeplitz solve
+    let t = matrix(vec![r[0], r[1], r[1], r[0]], 2, 2, Row);
+    let dense = t.solve(&vec![r[1], r[2]], LU);
+    assert!(eq_vec(&a, &dense, 1e-10));
+}
+
+#[test]
+fn test_get_window() {
+    let w = get_window(WindowKind::Hamming, 11);
+    assert!((w[0] - 0.08).abs() < 1e-12);
+    assert!((w[5] - 1f64).abs() < 1e-12);
+    assert!(eq_vec(
+        &w,
+        &w.iter().rev().cloned().collect::<Vec<f64>>(),
+        1e-12
+    ));
+    assert_eq!(get_window(WindowKind::Rectangular, 4), vec![1f64; 4]);
+    assert!(get_window(WindowKind::Blackman, 7)[0].abs() < 1e-12);
+    assert_eq!(get_window(WindowKind::Hann, 1), vec![1f64]);
+    assert!(get_window(WindowKind::Hann, 0).is_empty());
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_fft() {
+    // Compare with naive DFT for power of two and arbitrary lengths
+    for &n in &[1usize, 2, 8, 12, 17] {
+        let x = (0..n)
+            .map(|k| C64::new((k as f64).sin(), (0.3 * k as f64).cos()))
+            .collect::<Vec<C64>>();
+        let y = fft(&x);
+        for k in 0..n {
+            let mut s = C64::new(0f64, 0f64);
+            for j in 0..n {
+                let theta = -2f64 * std::f64::consts::PI * (j * k) as f64 / n as f64;
+                s += x[j] * C64::from_polar(1f64, theta);
+            }
+            assert!((y[k] - s).norm() < 1e-9);
+        }
+        let z = ifft(&y);
+        for k in 0..n {
+            assert!((z[k] - x[k]).norm() < 1e-12);
+        }
+    }
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_stft_chirp() {
+    use std::f64::consts::PI;
+    // Linear chirp: instantaneous frequency f(t) = 50 + 200 t (Hz), fs = 1000 Hz
+    let fs = 1000f64;
+    let x = (0..1000)
+        .map(|i| {
+            let t = i as f64 / fs;
+            (2f64 * PI * (50f64 * t + 100f64 * t * t)).sin()
+        })
+        .collect::<Vec<f64>>();
+    let n = 128;
+    let hop = 32;
+    let w = get_window(WindowKind::Hann, n);
+    let s = stft(&x, &w, hop);
+    assert_eq!(s.len(), (x.len() - n) / hop + 1);
+
+    let df = fs / n as f64;
+    let mut prev = 0f64;
+    for (m, frame) in s.iter().enumerate() {
+        let mag = frame[..n / 2]
+            .iter()
+            .map(|z| z.norm_sqr())
+            .collect::<Vec<f64>>();
+        let f_peak = mag.arg_max() as f64 * df;
+        let t_center = (m * hop + n / 2) as f64 / fs;
+        let f_inst = 50f64 + 200f64 * t_center;
+        assert!((f_peak - f_inst).abs() <= 1.5 * df);
+        assert!(f_peak >= prev);
+        prev = f_peak;
+    }
+
+    // Round trip (interior samples are covered by nonzero window weights)
+    let y = istft(&s, &w, hop);
+    assert_eq!(y.len(), (s.len() - 1) * hop + n);
+    for i in 1..y.len() - 1 {
+        assert!((y[i] - x[i]).abs() < 1e-9);
+    }
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_hilbert_envelope() {
+    use std::f64::consts::PI;
+    // AM signal: (1 + 0.5 cos(2 pi 2 t)) cos(2 pi 50 t), fs = 1000 Hz, 1 s
+    let fs = 1000f64;
+    let t = (0..1000).map(|i| i as f64 / fs).collect::<Vec<f64>>();
+    let modulation = t.fmap(|t| 1f64 + 0.5 * (2f64 * PI * 2f64 * t).cos());
+    let x = t
+        .iter()
+        .zip(modulation.iter())
+        .map(|(&t, &m)| m * (2f64 * PI * 50f64 * t).cos())
+        .collect::<Vec<f64>>();
+
+    let env = envelope(&x);
+    assert!(eq_vec(&env, &modulation, 1e-9));
+
+    let z = hilbert(&x);
+    assert!(eq_vec(
+        &z.iter().map(|z| z.re).collect::<Vec<f64>>(),
+        &x,
+        1e-12
+    ));
+
+    let phase = instantaneous_phase(&x);
+    for i in 0..phase.len() {
+        let expected = 2f64 * PI * 50f64 * t[i];
+        assert!((phase[i] - expected).abs() < 1e-9);
+    }
+
+    // Odd length
+    let y = (0..9)
+        .map(|k| (2f64 * PI * k as f64 / 9f64).sin())
+        .collect::<Vec<f64>>();
+    assert!(eq_vec(&envelope(&y), &vec![1f64; 9], 1e-12));
+}
+
+/// Magnitude of the DFT of `x` at frequency `f` (sampling rate `fs`), normalized by length
+fn dft_mag(x: &[f64], f: f64, fs: f64) -> f64 {
+    let w = 2f64 * std::f64::consts::PI * f / fs;
+    let (re, im) = x
+        .iter()
+        .enumerate()
+        .fold((0f64, 0f64), |(re, im), (i, &v)| {
+            (re + v * (w * i as f64).cos(), im - v * (w * i as f64).sin())
+        });
+    (re * re + im * im).sqrt() / x.len() as f64
+}
+
+#[test]
+fn test_resample() {
+    use std::f64::consts::PI;
+    let fs = 100f64;
+    let tone = |f: f64, n: usize, fs: f64| {
+        (0..n)
+            .map(|i| (2f64 * PI * f * i as f64 / fs).sin())
+            .collect::<Vec<f64>>()
+    };
+
+    // Upsample 100 Hz -> 150 Hz: 5 Hz tone preserved
+    let x = tone(5f64, 400, fs);
+    let y = resample(&x, 3, 2);
+    assert_eq!(y.len(), 600);
+    let expected = tone(5f64, 600, 150f64);
+    for i in 100..500 {
+        assert!((y[i] - expected[i]).abs() < 1e-3);
+    }
+
+    // Downsample 100 Hz -> 50 Hz: 40 Hz tone would alias to 10 Hz
+    let x = tone(5f64, 400, fs).add_v(&tone(40f64, 400, fs));
+    let y = resample(&x, 1, 2);
+    assert_eq!(y.len(), 200);
+    let interior = &y[40..160];
+    let signal = dft_mag(interior, 5f64, 50f64);
+    let alias = dft_mag(interior, 10f64, 50f64);
+    assert!((signal - 0.5).abs() < 0.01);
+    assert!(alias < 1e-3);
+
+    // Identity & odd length
+    assert_eq!(resample(&x, 4, 4), x);
+    assert_eq!(resample(&x[..7], 1, 3).len(), 3);
+}
+
+#[test]
+fn test_fir_filter() {
+    use std::f64::consts::PI;
+    let fs = 1000f64;
+    let n = 1000;
+    let low = (0..n)
+        .map(|i| (2f64 * PI * 50f64 * i as f64 / fs).sin())
+        .collect::<Vec<f64>>();
+    let high = (0..n)
+        .map(|i| (2f64 * PI * 300f64 * i as f64 / fs).sin())
+        .collect::<Vec<f64>>();
+    let x = low.add_v(&high);
+
+    // cutoff 100 Hz (0.2 of Nyquist)
+    let numtaps = 101;
+    let b = firwin(numtaps, 0.2, WindowKind::Hamming);
+    let y = filter(&b, &x);
+    assert_eq!(y.len(), x.len());
+
+    // Steady state, compensating the linear-phase delay
+    let delay = (numtaps - 1) / 2;
+    let steady = &y[numtaps..];
+    let low_part = &low[numtaps - delay..n - delay];
+    let residual = steady.to_vec().sub_v(&low_part.to_vec());
+    assert!(residual.norm(Norm::LInf) < 1e-2);
+    assert!(dft_mag(steady, 300f64, fs) < 1e-3);
+    assert!((dft_mag(steady, 50f64, fs) - 0.5).abs() < 1e-2);
+
+    // lfilter with a = [1] is FIR filtering
+    assert!(eq_vec(&lfilter(&b, &[1f64], &x), &y, 1e-12));
+    // normalization by a[0]
+    let z = lfilter(&[2f64], &[2f64, -1f64], &[1f64, 0f64, 0f64]);
+    assert!(eq_vec(&z, &vec![1f64, 0.5, 0.25], 1e-15));
+}
+
+/// |H(e^{i pi w})| for normalized frequency w (relative to Nyquist)
+#[cfg(feature = "complex")]
+fn freq_response(b: &[f64], a: &[f64], w: f64) -> f64 {
+    let theta = std::f64::consts::PI * w;
+    let eval = |c: &[f64]| {
+        c.iter()
+            .enumerate()
+            .fold((0f64, 0f64), |(re, im), (k, &ck)| {
+                (
+                    re + ck * (k as f64 * theta).cos(),
+                    im - ck * (k as f64 * theta).sin(),
+                )
+            })
+    };
+    let (br, bi) = eval(b);
+    let (ar, ai) = eval(a);
+    ((br * br + bi * bi) / (ar * ar + ai * ai)).sqrt()
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_butter() {
+    let half_power = 1f64 / 2f64.sqrt();
+    for order in 1..=6 {
+        let (b, a) = butter(order, 0.3, FilterKind::LowPass);
+        assert_eq!((b.len(), a.len()), (order + 1, order + 1));
+        assert!((freq_response(&b, &a, 0.3) - half_power).abs() < 1e-10);
+        assert!((freq_response(&b, &a, 0f64) - 1f64).abs() < 1e-10);
+        assert!(freq_response(&b, &a, 0.6) < half_power);
+
+        let (b, a) = butter(order, 0.4, FilterKind::HighPass);
+        assert!((freq_response(&b, &a, 0.4) - half_power).abs() < 1e-10);
+        assert!((freq_response(&b, &a, 1f64) - 1f64).abs() < 1e-10);
+        assert!(freq_response(&b, &a, 0f64) < 1e-10);
+
+        let (b, a) = butter(order, 0.2, FilterKind::BandPass(0.5));
+        assert_eq!((b.len(), a.len()), (2 * order + 1, 2 * order + 1));
+        assert!((freq_response(&b, &a, 0.2) - half_power).abs() < 1e-9);
+        assert!((freq_response(&b, &a, 0.5) - half_power).abs() < 1e-9);
+        assert!(freq_response(&b, &a, 0f64) < 1e-10);
+        assert!(freq_response(&b, &a, 1f64) < 1e-10);
+    }
+
+    // filtfilt: zero phase and squared magnitude (-6 dB at cutoff)
+    let (b, a) = butter(3, 0.3, FilterKind::LowPass);
+    let x = (0..2000)
+        .map(|i| (std::f64::consts::PI * 0.3 * i as f64).sin())
+        .collect::<Vec<f64>>();
+    let y = filtfilt(&b, &a, &x);
+    for i in 500..1500 {
+        assert!((y[i] - 0.5 * x[i]).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_find_peaks() {
+    // peaks at 10 (h=1), 14 (h=3), 30 (h=0.5), 50 (h=2), 53 (h=2.5), 80 (h=4)
+    let mut x = vec![0f64; 100];
+    for &(i, h) in &[
+        (10, 1f64),
+        (14, 3f64),
+        (30, 0.5),
+        (50, 2f64),
+        (53, 2.5),
+        (80, 4f64),
+    ] {
+        x[i] = h;
+    }
+    assert_eq!(find_peaks(&x, None, None), vec![10, 14, 30, 50, 53, 80]);
+    assert_eq!(find_peaks(&x, Some(1.5), None), vec![14, 50, 53, 80]);
+
+    let peaks = find_peaks(&x, None, Some(5));
+    assert_eq!(peaks, vec![14, 30, 53, 80]);
+    for w in peaks.windows(2) {
+        assert!(w[1] - w[0] >= 5);
+    }
+    assert_eq!(find_peaks(&x, Some(1f64), Some(5)), vec![14, 53, 80]);
+
+    // Plateau and end points
+    let y = vec![5f64, 1f64, 2f64, 2f64, 2f64, 1f64, 3f64];
+    assert_eq!(find_peaks(&y, None, None), vec![3]);
+}
@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_softplus() {
+    let y = softplus(1000f64);
+    assert!(y.is_finite());
+    assert_eq!(y, 1000f64);
+    assert!(softplus(-1000f64) >= 0f64);
+
+    for x in seq(-20, 20, 0.5) {
+        let naive = (1f64 + x.exp()).ln();
+        assert!((softplus(x) - naive).abs() < 1e-12 * naive.max(1f64));
+    }
+
+    let v = c!(-1, 0, 1);
+    assert_eq!(v.softplus(), v.fmap(softplus));
+    assert_eq!(v.log1p().expm1(), v);
+    let m = ml_matrix("1e-20 1;2 3");
+    assert_eq!(m.log1p()[(0, 0)], 1e-20);
+    assert_eq!(m.softplus()[(1, 1)], softplus(3f64));
+}
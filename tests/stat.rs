@@ -0,0 +1,195 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_summary() {
+    let x = c!(2.5, -1.0, 4.0, 7.5, 3.0, 0.5);
+    let s = summary(&x);
+    assert_eq!(s.count, x.len());
+    assert_eq!(s.min, x.min());
+    assert_eq!(s.max, x.max());
+    assert!(nearly_eq(s.mean, x.mean()));
+    assert!(nearly_eq(s.var, x.var()));
+
+    let empty = summary(&[]);
+    assert_eq!(empty.count, 0);
+    assert!(empty.mean.is_nan());
+}
+
+#[test]
+fn test_vec_statistics() {
+    let x = c!(1, 2, 3, 4);
+    assert_eq!(x.mean(), 2.5);
+    assert!(nearly_eq(x.var(), 5f64 / 3f64));
+    assert!(nearly_eq(x.std(), (5f64 / 3f64).sqrt()));
+    assert_eq!(x.std(), x.sd());
+    assert!(nearly_eq(x.var_pop(), 1.25));
+    assert!(nearly_eq(x.std_pop(), 1.25f64.sqrt()));
+
+    let y = c!(2, 4, 6, 8);
+    assert!(nearly_eq(cov(&x, &y), 10f64 / 3f64));
+    assert!(nearly_eq(cov(&x, &x), x.var()));
+
+    let empty: Vec<f64> = vec![];
+    assert!(empty.var().is_nan());
+    assert!(empty.std().is_nan());
+    assert!(empty.var_pop().is_nan());
+    assert!(c!(1).var().is_nan());
+    assert!(c!(1).std().is_nan());
+    assert!(cov(&c!(1), &c!(2)).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_cov_length_mismatch() {
+    cov(&c!(1, 2, 3), &c!(1, 2));
+}
+
+#[test]
+fn test_mode() {
+    let unimodal = vec![3f64, 1f64, 4f64, 1f64, 5f64, 9f64, 2f64, 6f64, 1f64];
+    assert_eq!(mode(&unimodal, 0f64), vec![1f64]);
+
+    let bimodal = vec![2f64, 7f64, 2f64, 7f64, 3f64, 2f64, 7f64, 5f64];
+    assert_eq!(mode(&bimodal, 0f64), vec![2f64, 7f64]);
+
+    let noisy = vec![1.98, 2.0, 2.02, 6.99, 7.01, 7.0, 4.0];
+    let m = mode(&noisy, 0.05);
+    assert_eq!(m.len(), 2);
+    assert!((m[0] - 2f64).abs() < 1e-12);
+    assert!((m[1] - 7f64).abs() < 1e-12);
+
+    assert!(mode(&[], 0f64).is_empty());
+}
+
+#[test]
+fn test_remove_outliers() {
+    let data = vec![4.8, 5.1, 5.0, 4.9, 5.2, 5.0, 42.0, 5.1, 4.9, 5.0];
+    let normal: Vec<f64> = data.iter().copied().filter(|&x| x < 10.0).collect();
+
+    let by_iqr = remove_outliers_iqr(&data, 1.5);
+    assert_eq!(by_iqr, normal);
+
+    let by_z = remove_outliers_zscore(&data, 2.5);
+    assert_eq!(by_z, normal);
+
+    let constant = vec![3f64; 5];
+    assert_eq!(remove_outliers_zscore(&constant, 1.0), constant);
+    assert_eq!(remove_outliers_iqr(&constant, 1.5), constant);
+    assert!(remove_outliers_iqr(&[], 1.5).is_empty());
+}
+
+#[test]
+fn test_standard_scaler() {
+    let train = vec![2.3, -1.7, 8.9, 4.4, 0.5, 3.1];
+    let (scaler, z) = StandardScaler::fit_transform(&train);
+    assert!(z.mean().abs() < 1e-12);
+    assert!((z.sd() - 1f64).abs() < 1e-12);
+    assert!(eq_vec(&scaler.inverse_transform(&z), &train, 1e-12));
+
+    // Parameters learned on training data are reused for new data
+    let test = vec![-10f64, 0f64, 100f64];
+    let back = scaler.inverse_transform(&scaler.transform(&test));
+    assert!(eq_vec(&back, &test, 1e-12));
+    assert_eq!(scaler.transform(&[scaler.mean]), vec![0f64]);
+
+    // Constant data: centered only
+    let constant = StandardScaler::fit(&[5f64; 4]);
+    assert_eq!(constant.std, 1f64);
+    assert_eq!(constant.transform(&[5f64, 6f64]), vec![0f64, 1f64]);
+}
+
+#[test]
+fn test_min_max_scaler() {
+    let train = vec![3.5, -2f64, 7.25, 0.1, 1f64];
+    let (scaler, y) = MinMaxScaler::fit_transform(&train);
+    assert_eq!(y[1], 0f64);
+    assert_eq!(y[2], 1f64);
+    assert!(y.iter().all(|&t| (0f64..=1f64).contains(&t)));
+    assert!(eq_vec(&scaler.inverse_transform(&y), &train, 1e-12));
+
+    // Custom range maps the fitted extremes exactly onto the endpoints
+    let mut scaler = scaler;
+    scaler.set_range(-5f64, 5f64);
+    assert_eq!(scaler.transform(&[-2f64, 7.25]), vec![-5f64, 5f64]);
+
+    // Out-of-range values: extrapolate or clip
+    assert!(scaler.transform(&[20f64])[0] > 5f64);
+    scaler.set_clip(true);
+    assert_eq!(scaler.transform(&[-100f64, 20f64]), vec![-5f64, 5f64]);
+
+    // Constant data
+    let constant = MinMaxScaler::fit(&[4f64; 3]);
+    assert_eq!(constant.transform(&[4f64, 5f64]), vec![0f64, 1f64]);
+}
+
+#[test]
+fn test_pdf_cdf_round_trip() {
+    let normal = Normal(0f64, 1f64);
+    let x = linspace(-8, 8, 801);
+    let pdf = x.fmap(|t| normal.pdf(t));
+
+    let cdf = pdf_to_cdf(&x, &pdf);
+    assert_eq!(cdf[0], 0f64);
+    assert_eq!(cdf[800], 1f64);
+    assert!(cdf.windows(2).all(|w| w[0] <= w[1]));
+    for (&t, &c) in x.iter().zip(cdf.iter()) {
+        assert!((c - normal.cdf(t)).abs() < 1e-4);
+    }
+
+    let back = cdf_to_pdf(&x, &cdf);
+    for (&p, &q) in pdf.iter().zip(back.iter()) {
+        assert!((p - q).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_geometric_harmonic_mean() {
+    assert!((geometric_mean(&[1f64, 10f64, 100f64]) - 10f64).abs() < 1e-12);
+    assert!((harmonic_mean(&[1f64, 10f64, 100f64]) - 300f64 / 111f64).abs() < 1e-12);
+
+    let data = vec![
+        vec![1f64, 10f64, 100f64],
+        vec![0.5, 2f64, 3.5, 7f64, 11f64],
+        vec![1e-3, 1e3],
+        vec![4.2; 5],
+    ];
+    for x in data {
+        let (am, gm, hm) = (x.mean(), x.geometric_mean(), x.harmonic_mean());
+        assert!(am >= gm * (1f64 - 1e-14), "AM < GM for {:?}", x);
+        assert!(gm >= hm * (1f64 - 1e-14), "GM < HM for {:?}", x);
+        assert_eq!(gm, geometric_mean(&x));
+        assert_eq!(hm, harmonic_mean(&x));
+    }
+
+    let m = matrix(c!(1, 10, 100, 1, 4, 4), 3, 2, Col);
+    let gm = m.geometric_mean();
+    let hm = m.harmonic_mean();
+    assert!((gm[0] - 10f64).abs() < 1e-12);
+    assert!((gm[1] - 16f64.powf(1f64 / 3f64)).abs() < 1e-12);
+    assert!((hm[0] - 300f64 / 111f64).abs() < 1e-12);
+    assert_eq!(hm[1], 2f64);
+}
+
+#[test]
+#[should_panic]
+fn test_geometric_mean_non_positive() {
+    geometric_mean(&[1f64, 0f64, 2f64]);
+}
+
+#[test]
+fn test_var_large_mean() {
+    // Naive E[x^2] - E[x]^2 loses all precision here (it can even be negative)
+    let a = vec![1e8 + 1f64, 1e8 + 2f64, 1e8 + 3f64];
+    assert!((a.var() - 1f64).abs() < 1e-12);
+    assert!((a.sd() - 1f64).abs() < 1e-12);
+    assert!((a.var_pop() - 2f64 / 3f64).abs() < 1e-12);
+    assert!((cov(&a, &a) - 1f64).abs() < 1e-12);
+
+    let b = vec![1e9 + 4f64, 1e9 + 7f64, 1e9 + 13f64, 1e9 + 16f64];
+    assert!((b.var() - 30f64).abs() < 1e-9);
+    let m = matrix(b.clone(), 4, 1, Col);
+    assert!((m.var()[0] - 30f64).abs() < 1e-9);
+    assert!((summary(&b).var - b.var()).abs() < 1e-9);
+}
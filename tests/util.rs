@@ -0,0 +1,39 @@
+use peroxide::fuga::*;
+
+#[test]
+fn test_seq_int() {
+    assert_eq!(seq_int(-2, 2, 1), vec![-2i64, -1, 0, 1, 2]);
+    assert_eq!(seq_int(0, 5, 10), vec![0i64]);
+    assert!(seq_int(5, 0, 1).is_empty());
+    let big = 1i64 << 60;
+    assert_eq!(seq_int(big, big + 2, 1), vec![big, big + 1, big + 2]);
+    assert_eq!(seq_int(i64::MAX - 1, i64::MAX, 1).len(), 2);
+    assert_eq!(range_usize(1, 7, 2), vec![1usize, 3, 5, 7]);
+    assert!(range_usize(3, 1, 1).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_seq_int_zero_step() {
+    seq_int(0, 1, 0);
+}
+
+#[test]
+fn test_piecewise_linspace() {
+    let a = piecewise_linspace(&[0f64, 1f64, 3f64], &[3, 5]);
+    assert_eq!(a, vec![0f64, 0.5, 1f64, 1.5, 2f64, 2.5, 3f64]);
+    let b = piecewise_linspace(&[0f64, 1f64, 2f64], &[2, 2]);
+    assert_eq!(b, vec![0f64, 1f64, 2f64]);
+}
+
+#[test]
+#[should_panic(expected = "at least 2")]
+fn test_piecewise_linspace_count_one() {
+    piecewise_linspace(&[0f64, 1f64, 2f64], &[3, 1]);
+}
+
+#[test]
+#[should_panic(expected = "at least 2")]
+fn test_piecewise_linspace_count_zero() {
+    piecewise_linspace(&[0f64, 1f64], &[0]);
+}
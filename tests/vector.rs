@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate peroxide;
+use peroxide::fuga::*;
+
+#[test]
+fn test_concat_non_copy() {
+    let a = vec!["x".to_string(), "y".to_string()];
+    let b = vec!["z".to_string()];
+    assert_eq!(concat(&a, &b), vec!["x", "y", "z"]);
+    assert_eq!(cat("w".to_string(), &a), vec!["w", "x", "y"]);
+
+    let u = c!(1, 2);
+    let v = c!(3, 4);
+    assert_eq!(concat(&u, &v), c!(1, 2, 3, 4));
+    assert_eq!(cat(0f64, &u), c!(0, 1, 2));
+}
+
+#[test]
+fn test_reduce1_scan() {
+    let a = c!(1, 2, 3, 4, 5);
+    assert_eq!(a.reduce1(|x, y| x * y), Some(120f64));
+    assert_eq!(Vec::<f64>::new().reduce1(|x, y| x * y), None);
+
+    assert_eq!(a.scan(0, |x, y| x + y), c!(1, 3, 6, 10, 15));
+    assert_eq!(a.scan(0, |x, y| x + y), a.cumsum());
+    assert_eq!(a.scan(1, |x, y| x * y), a.cumprod());
+    assert!(Vec::<f64>::new().scan(0, |x, y| x + y).is_empty());
+}
+
+#[test]
+fn test_zip_with3_4() {
+    let a = c!(1, 2, 3, 4);
+    let b = c!(0.5, -1, 2, 3);
+    let c = c!(10, 20, 30, 40);
+    let d = c!(1, 1, 2, 2);
+
+    let fused = a.zip_with3(|x, y, z| x * y + z, &b, &c);
+    let chained = a.zip_with(|x, y| x * y, &b).zip_with(|x, y| x + y, &c);
+    assert_eq!(fused, chained);
+
+    let fused4 = a.zip_with4(|x, y, z, w| (x * y + z) / w, &b, &c, &d);
+    let chained4 = chained.zip_with(|x, y| x / y, &d);
+    assert_eq!(fused4, chained4);
+}
+
+#[test]
+#[should_panic(expected = "zip_with3: length mismatch")]
+fn test_zip_with3_mismatch() {
+    let a = c!(1, 2, 3);
+    a.zip_with3(|x, y, z| x + y + z, &c!(1, 2), &c!(1, 2, 3));
+}
+
+#[test]
+fn test_chunks_windows_vec() {
+    let a = c!(1, 2, 3, 4, 5);
+    assert_eq!(a.chunks_vec(2), vec![c!(1, 2), c!(3, 4), c!(5)]);
+    assert_eq!(a.chunks_vec(5), vec![a.clone()]);
+    assert_eq!(a.windows_vec(2), vec![c!(1, 2), c!(2, 3), c!(3, 4), c!(4, 5)]);
+    assert!(a.windows_vec(6).is_empty());
+    assert_eq!(a.take_while(|x| x < 3f64), c!(1, 2));
+    assert_eq!(a.drop_while(|x| x < 3f64), c!(3, 4, 5));
+    // slice methods are still reachable
+    assert_eq!(a.chunks(2).count(), 3);
+}